@@ -0,0 +1,385 @@
+use crate::{
+    fee_set::FeeSet,
+    leverage::Leverage,
+    margin::Margin,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct AssetPair {
+    name: String,
+    details: Option<AssetPairDetails>,
+    leverage: Option<AssetPairLeverage>,
+    fees: Option<AssetPairFees>,
+    margins: Option<AssetPairMargins>,
+}
+
+#[derive(Debug)]
+pub struct AssetPairDetails {
+    altname: String,
+    wsname: String,
+    aclass_base: String,
+    base: String,
+    aclass_quote: String,
+    quote: String,
+    lot: String,
+    pair_decimals: i32,
+    lot_decimals: i32,
+    lot_multiplier: i32,
+    order_min: String,
+}
+
+#[derive(Debug)]
+pub struct AssetPairLeverage {
+    leverage_buy: Leverage,
+    leverage_sell: Leverage,
+}
+
+#[derive(Debug)]
+pub struct AssetPairFees {
+    fees: FeeSet,
+    fees_maker: FeeSet,
+    fee_volume_currency: String,
+}
+
+#[derive(Debug)]
+pub struct AssetPairMargins {
+    margin_call: Margin,
+    margin_stop: Margin,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssetPairInfo {
+    Info,
+    Leverage,
+    Fees,
+    Margin,
+}
+
+impl AssetPairInfo {
+    pub fn value(&self) -> &'static str {
+        match self {
+            AssetPairInfo::Info => "info",
+            AssetPairInfo::Leverage => "leverage",
+            AssetPairInfo::Fees => "fees",
+            AssetPairInfo::Margin => "margin",
+        }
+    }
+}
+
+impl AssetPair {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn details(&self) -> Option<&AssetPairDetails> {
+        self.details.as_ref()
+    }
+
+    pub fn leverage(&self) -> Option<&AssetPairLeverage> {
+        self.leverage.as_ref()
+    }
+
+    pub fn fees(&self) -> Option<&AssetPairFees> {
+        self.fees.as_ref()
+    }
+
+    pub fn margins(&self) -> Option<&AssetPairMargins> {
+        self.margins.as_ref()
+    }
+}
+
+impl AssetPairDetails {
+    pub fn altname(&self) -> &String {
+        &self.altname
+    }
+
+    pub fn base(&self) -> &String {
+        &self.base
+    }
+
+    pub fn quote(&self) -> &String {
+        &self.quote
+    }
+
+    pub fn pair_decimals(&self) -> i32 {
+        self.pair_decimals
+    }
+
+    pub fn lot_decimals(&self) -> i32 {
+        self.lot_decimals
+    }
+
+    pub fn order_min(&self) -> &String {
+        &self.order_min
+    }
+}
+
+impl AssetPairLeverage {
+    pub fn leverage_buy(&self) -> &Leverage {
+        &self.leverage_buy
+    }
+
+    pub fn leverage_sell(&self) -> &Leverage {
+        &self.leverage_sell
+    }
+}
+
+impl AssetPairFees {
+    pub fn fees(&self) -> &FeeSet {
+        &self.fees
+    }
+
+    pub fn fees_maker(&self) -> &FeeSet {
+        &self.fees_maker
+    }
+
+    pub fn fee_volume_currency(&self) -> &String {
+        &self.fee_volume_currency
+    }
+}
+
+impl AssetPairMargins {
+    pub fn margin_call(&self) -> &Margin {
+        &self.margin_call
+    }
+
+    pub fn margin_stop(&self) -> &Margin {
+        &self.margin_stop
+    }
+}
+
+pub fn new(
+    name: String,
+    details: Option<AssetPairDetails>,
+    leverage: Option<AssetPairLeverage>,
+    fees: Option<AssetPairFees>,
+    margins: Option<AssetPairMargins>,
+) -> Result<AssetPair, ValidationError> {
+    let _ = validate_name(&name)?;
+    Ok(AssetPair {
+        name: name,
+        details: details,
+        leverage: leverage,
+        fees: fees,
+        margins: margins,
+    })
+}
+
+pub fn new_details(
+    altname: String,
+    wsname: String,
+    aclass_base: String,
+    base: String,
+    aclass_quote: String,
+    quote: String,
+    lot: String,
+    pair_decimals: i32,
+    lot_decimals: i32,
+    lot_multiplier: i32,
+    order_min: String,
+) -> Result<AssetPairDetails, ValidationError> {
+    let _ = validate_decimals(pair_decimals, lot_decimals)?;
+    Ok(AssetPairDetails {
+        altname: altname,
+        wsname: wsname,
+        aclass_base: aclass_base,
+        base: base,
+        aclass_quote: aclass_quote,
+        quote: quote,
+        lot: lot,
+        pair_decimals: pair_decimals,
+        lot_decimals: lot_decimals,
+        lot_multiplier: lot_multiplier,
+        order_min: order_min,
+    })
+}
+
+pub fn new_leverage(
+    leverage_buy: Leverage,
+    leverage_sell: Leverage,
+) -> Result<AssetPairLeverage, ValidationError> {
+    Ok(AssetPairLeverage {
+        leverage_buy: leverage_buy,
+        leverage_sell: leverage_sell,
+    })
+}
+
+pub fn new_fees(
+    fees: FeeSet,
+    fees_maker: FeeSet,
+    fee_volume_currency: String,
+) -> Result<AssetPairFees, ValidationError> {
+    Ok(AssetPairFees {
+        fees: fees,
+        fees_maker: fees_maker,
+        fee_volume_currency: fee_volume_currency,
+    })
+}
+
+pub fn new_margins(
+    margin_call: Margin,
+    margin_stop: Margin,
+) -> Result<AssetPairMargins, ValidationError> {
+    let _ = validate_margins(&margin_call, &margin_stop)?;
+    Ok(AssetPairMargins {
+        margin_call: margin_call,
+        margin_stop: margin_stop,
+    })
+}
+
+fn validate_name(name: &String) -> Result<(), ValidationError> {
+    if name.is_empty() {
+        Err(validation_error::new("asset pair name cannot be empty"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_decimals(pair_decimals: i32, lot_decimals: i32) -> Result<(), ValidationError> {
+    if pair_decimals < 0 || lot_decimals < 0 {
+        Err(validation_error::new(
+            "pair and lot decimals cannot be negative",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_margins(margin_call: &Margin, margin_stop: &Margin) -> Result<(), ValidationError> {
+    if margin_call.value() < margin_stop.value() {
+        Err(validation_error::new(
+            "margin stop cannot be larger than margin call",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{fee_set::FeeSet, leverage::Leverage, margin::Margin};
+
+    use super::{new, new_details, new_fees, new_leverage, new_margins};
+
+    #[test]
+    fn should_new_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let name = String::from("XXBTZUSD");
+        let details = new_details(
+            String::from("XBTUSD"),
+            String::from("XBT/USD"),
+            String::from("currency"),
+            String::from("XXBT"),
+            String::from("currency"),
+            String::from("ZUSD"),
+            String::from("unit"),
+            1,
+            8,
+            1,
+            String::from("0.0001"),
+        )
+        .unwrap();
+        let leverage = new_leverage(
+            Leverage {
+                value: vec![2, 3, 4, 5],
+            },
+            Leverage {
+                value: vec![2, 3, 4, 5],
+            },
+        )
+        .unwrap();
+        let fees = [(0, 0.26), (50000, 0.24), (100000, 0.22)]
+            .into_iter()
+            .clone()
+            .collect::<BTreeMap<u32, f32>>();
+        let fees_maker = [(0, 0.16), (50000, 0.14), (100000, 0.12)]
+            .into_iter()
+            .clone()
+            .collect::<BTreeMap<u32, f32>>();
+        let fees = new_fees(
+            FeeSet { value: fees },
+            FeeSet { value: fees_maker },
+            String::from("ZUSD"),
+        )
+        .unwrap();
+        let margins = new_margins(Margin { value: 80 }, Margin { value: 40 }).unwrap();
+
+        //Act
+        let result = new(
+            name,
+            Some(details),
+            Some(leverage),
+            Some(fees),
+            Some(margins),
+        );
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_ok_if_only_part_of_the_info_is_provided() {
+        //Arrange
+        let name = String::from("XXBTZUSD");
+        let margins = new_margins(Margin { value: 80 }, Margin { value: 40 }).unwrap();
+
+        //Act
+        let result = new(name, None, None, None, Some(margins));
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_name_is_empty() {
+        //Arrange
+        let name = String::from("");
+
+        //Act
+        let result = new(name, None, None, None, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_details_return_error_if_decimals_are_negative() {
+        //Arrange
+        let pair_decimals = -1;
+        let lot_decimals = 8;
+
+        //Act
+        let result = new_details(
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            String::from(""),
+            pair_decimals,
+            lot_decimals,
+            1,
+            String::from(""),
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_margins_return_error_if_margin_stop_is_above_margin_call() {
+        //Arrange
+        let margin_call = 80;
+        let margin_stop = 90;
+
+        //Act
+        let result = new_margins(Margin { value: margin_call }, Margin { value: margin_stop });
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
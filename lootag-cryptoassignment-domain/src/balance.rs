@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use crate::{
+    currency_amount::CurrencyAmount,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct Balance {
+    asset: String,
+    balance: CurrencyAmount,
+    hold_trade: Option<CurrencyAmount>,
+}
+
+#[derive(Debug)]
+pub struct Balances {
+    balances: HashMap<String, Balance>,
+}
+
+impl Balance {
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn balance(&self) -> &CurrencyAmount {
+        &self.balance
+    }
+
+    pub fn hold_trade(&self) -> Option<&CurrencyAmount> {
+        self.hold_trade.as_ref()
+    }
+
+    pub fn available(&self) -> f32 {
+        self.balance.value() - self.hold_trade.as_ref().map_or(0.0, |h| h.value())
+    }
+}
+
+impl Balances {
+    pub fn get(&self, asset: &str) -> Option<&Balance> {
+        self.balances.get(asset)
+    }
+
+    pub fn assets(&self) -> Vec<&String> {
+        self.balances.keys().collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Balance> {
+        self.balances.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.balances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.balances.is_empty()
+    }
+}
+
+pub fn new(
+    asset: String,
+    balance: CurrencyAmount,
+    hold_trade: Option<CurrencyAmount>,
+) -> Result<Balance, ValidationError> {
+    let _ = validate_asset(&asset)?;
+    let _ = validate_hold_trade_does_not_exceed_balance(&balance, &hold_trade)?;
+    Ok(Balance {
+        asset: asset,
+        balance: balance,
+        hold_trade: hold_trade,
+    })
+}
+
+pub fn new_balances(balances: Vec<Balance>) -> Result<Balances, ValidationError> {
+    let mut balances_by_asset = HashMap::new();
+    for balance in balances {
+        if balances_by_asset.contains_key(&balance.asset) {
+            return Err(validation_error::new(
+                "an asset cannot appear more than once in the balances",
+            ));
+        }
+        balances_by_asset.insert(balance.asset.clone(), balance);
+    }
+    Ok(Balances {
+        balances: balances_by_asset,
+    })
+}
+
+fn validate_asset(asset: &String) -> Result<(), ValidationError> {
+    if asset.is_empty() {
+        Err(validation_error::new("asset cannot be empty"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_hold_trade_does_not_exceed_balance(
+    balance: &CurrencyAmount,
+    hold_trade: &Option<CurrencyAmount>,
+) -> Result<(), ValidationError> {
+    match hold_trade {
+        Some(hold_trade) if hold_trade.value() > balance.value() => Err(validation_error::new(
+            "amount held in trades cannot exceed the balance",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_amount;
+
+    use super::{new, new_balances};
+
+    #[test]
+    fn should_new_return_error_if_hold_trade_exceeds_balance() {
+        //Arrange
+        let balance = currency_amount::new(1.5).unwrap();
+        let hold_trade = currency_amount::new(2.0).unwrap();
+
+        //Act
+        let result = new(String::from("XXBT"), balance, Some(hold_trade));
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_available_subtract_the_amount_held_in_trades() {
+        //Arrange
+        let balance = new(
+            String::from("XXBT"),
+            currency_amount::new(1.5).unwrap(),
+            Some(currency_amount::new(0.5).unwrap()),
+        )
+        .unwrap();
+
+        //Act
+        let available = balance.available();
+
+        //Assert
+        assert_eq!(available, 1.0);
+    }
+
+    #[test]
+    fn should_new_balances_return_error_if_an_asset_is_repeated() {
+        //Arrange
+        let balances = vec![
+            new(String::from("ZUSD"), currency_amount::new(10.0).unwrap(), None).unwrap(),
+            new(String::from("ZUSD"), currency_amount::new(20.0).unwrap(), None).unwrap(),
+        ];
+
+        //Act
+        let result = new_balances(balances);
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
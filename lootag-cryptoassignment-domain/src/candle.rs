@@ -0,0 +1,173 @@
+use crate::{
+    currency_amount::CurrencyAmount,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct Candle {
+    time: u64,
+    open: CurrencyAmount,
+    high: CurrencyAmount,
+    low: CurrencyAmount,
+    close: CurrencyAmount,
+    vwap: CurrencyAmount,
+    volume: CurrencyAmount,
+    count: u32,
+}
+
+impl Candle {
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    pub fn open(&self) -> &CurrencyAmount {
+        &self.open
+    }
+
+    pub fn high(&self) -> &CurrencyAmount {
+        &self.high
+    }
+
+    pub fn low(&self) -> &CurrencyAmount {
+        &self.low
+    }
+
+    pub fn close(&self) -> &CurrencyAmount {
+        &self.close
+    }
+
+    pub fn vwap(&self) -> &CurrencyAmount {
+        &self.vwap
+    }
+
+    pub fn volume(&self) -> &CurrencyAmount {
+        &self.volume
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+pub fn new(
+    time: u64,
+    open: CurrencyAmount,
+    high: CurrencyAmount,
+    low: CurrencyAmount,
+    close: CurrencyAmount,
+    vwap: CurrencyAmount,
+    volume: CurrencyAmount,
+    count: u32,
+) -> Result<Candle, ValidationError> {
+    let _ = validate_prices(&open, &high, &low, &close)?;
+    Ok(Candle {
+        time: time,
+        open: open,
+        high: high,
+        low: low,
+        close: close,
+        vwap: vwap,
+        volume: volume,
+        count: count,
+    })
+}
+
+fn validate_prices(
+    open: &CurrencyAmount,
+    high: &CurrencyAmount,
+    low: &CurrencyAmount,
+    close: &CurrencyAmount,
+) -> Result<(), ValidationError> {
+    let is_high_valid = high.value() >= open.value() && high.value() >= close.value();
+    let is_low_valid = low.value() <= open.value() && low.value() <= close.value();
+    if is_high_valid && is_low_valid {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "open and close prices need to be between the low and the high",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_amount::{self, CurrencyAmount};
+
+    use super::new;
+
+    #[test]
+    fn should_new_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let open = 30306.1;
+        let high = 30310.0;
+        let low = 30300.0;
+        let close = 30305.7;
+
+        //Act
+        let result = new(
+            1688671200,
+            amount(open),
+            amount(high),
+            amount(low),
+            amount(close),
+            amount(30306.1),
+            amount(3.39),
+            23,
+        );
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_high_is_below_close() {
+        //Arrange
+        let open = 30306.1;
+        let high = 30306.2;
+        let low = 30300.0;
+        let close = 30310.0;
+
+        //Act
+        let result = new(
+            1688671200,
+            amount(open),
+            amount(high),
+            amount(low),
+            amount(close),
+            amount(30306.1),
+            amount(3.39),
+            23,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_low_is_above_open() {
+        //Arrange
+        let open = 30306.1;
+        let high = 30310.0;
+        let low = 30307.0;
+        let close = 30308.0;
+
+        //Act
+        let result = new(
+            1688671200,
+            amount(open),
+            amount(high),
+            amount(low),
+            amount(close),
+            amount(30306.1),
+            amount(3.39),
+            23,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn amount(value: f32) -> CurrencyAmount {
+        currency_amount::new(value).unwrap()
+    }
+}
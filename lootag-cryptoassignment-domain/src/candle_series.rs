@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+
+use is_sorted::IsSorted;
+
+use crate::{
+    candle::Candle,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct CandleSeries {
+    pair: String,
+    interval: Interval,
+    candles: Vec<Candle>,
+    last: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Interval {
+    OneMinute,
+    FiveMinutes,
+    FifteenMinutes,
+    ThirtyMinutes,
+    OneHour,
+    FourHours,
+    OneDay,
+    OneWeek,
+    FifteenDays,
+}
+
+impl Interval {
+    pub fn minutes(&self) -> u32 {
+        match self {
+            Interval::OneMinute => 1,
+            Interval::FiveMinutes => 5,
+            Interval::FifteenMinutes => 15,
+            Interval::ThirtyMinutes => 30,
+            Interval::OneHour => 60,
+            Interval::FourHours => 240,
+            Interval::OneDay => 1440,
+            Interval::OneWeek => 10080,
+            Interval::FifteenDays => 21600,
+        }
+    }
+}
+
+impl CandleSeries {
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn interval(&self) -> Interval {
+        self.interval
+    }
+
+    pub fn candles(&self) -> &Vec<Candle> {
+        &self.candles
+    }
+
+    pub fn last(&self) -> u64 {
+        self.last
+    }
+}
+
+pub fn new(
+    pair: String,
+    interval: Interval,
+    candles: Vec<Candle>,
+    last: u64,
+) -> Result<CandleSeries, ValidationError> {
+    let _ = validate_timestamps_are_strictly_increasing(&candles)?;
+    Ok(CandleSeries {
+        pair: pair,
+        interval: interval,
+        candles: candles,
+        last: last,
+    })
+}
+
+fn validate_timestamps_are_strictly_increasing(
+    candles: &Vec<Candle>,
+) -> Result<(), ValidationError> {
+    fn compare_strictly(time1: &u64, time2: &u64) -> Option<Ordering> {
+        if time1 < time2 {
+            Some(Ordering::Less)
+        } else {
+            None
+        }
+    }
+    let are_timestamps_valid =
+        IsSorted::is_sorted_by(&mut candles.iter().map(|c| c.time()), compare_strictly);
+    if are_timestamps_valid {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "candle timestamps need to be strictly increasing",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        candle::{self, Candle},
+        currency_amount,
+    };
+
+    use super::{new, Interval};
+
+    #[test]
+    fn should_new_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let candles = vec![candle(1688671200), candle(1688671260), candle(1688671320)];
+
+        //Act
+        let result = new(
+            String::from("XXBTZUSD"),
+            Interval::OneMinute,
+            candles,
+            1688671260,
+        );
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_ok_if_there_are_no_candles() {
+        //Arrange
+        let candles = vec![];
+
+        //Act
+        let result = new(
+            String::from("XXBTZUSD"),
+            Interval::OneMinute,
+            candles,
+            1688671260,
+        );
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_timestamps_are_repeated() {
+        //Arrange
+        let candles = vec![candle(1688671200), candle(1688671200)];
+
+        //Act
+        let result = new(
+            String::from("XXBTZUSD"),
+            Interval::OneMinute,
+            candles,
+            1688671200,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_timestamps_are_decreasing() {
+        //Arrange
+        let candles = vec![candle(1688671260), candle(1688671200)];
+
+        //Act
+        let result = new(
+            String::from("XXBTZUSD"),
+            Interval::OneMinute,
+            candles,
+            1688671200,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn candle(time: u64) -> Candle {
+        candle::new(
+            time,
+            currency_amount::new(30306.1).unwrap(),
+            currency_amount::new(30310.0).unwrap(),
+            currency_amount::new(30300.0).unwrap(),
+            currency_amount::new(30305.7).unwrap(),
+            currency_amount::new(30306.1).unwrap(),
+            currency_amount::new(3.39).unwrap(),
+            23,
+        )
+        .unwrap()
+    }
+}
@@ -1,16 +1,22 @@
-use std::{io::ErrorKind};
+use crate::validation_error::{self, ValidationError};
 
-#[derive(Debug)]
+//Only holds the figures kraken reports for display. Amounts that are sent to kraken
+//or reconciled against it are plain f64, since an f32 can't hold the eight
+//decimals kraken allows for most assets
+#[derive(Debug, Clone)]
 pub struct CurrencyAmount {
     value: f32,
 }
 
-pub fn new(value: f32) -> Result<CurrencyAmount, std::io::Error> {
+impl CurrencyAmount {
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+}
+
+pub fn new(value: f32) -> Result<CurrencyAmount, ValidationError> {
     if value < 0.0000 {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
-            "currency amount cannot be negative",
-        ))
+        Err(validation_error::new("currency amount cannot be negative"))
     } else {
         Ok(CurrencyAmount { value: value })
     }
@@ -0,0 +1,79 @@
+use chrono::{DateTime, Utc};
+
+use crate::validation_error::{self, ValidationError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeadManSwitch {
+    current_time: DateTime<Utc>,
+    trigger_time: Option<DateTime<Utc>>,
+}
+
+impl DeadManSwitch {
+    pub fn current_time(&self) -> DateTime<Utc> {
+        self.current_time
+    }
+
+    //None when the switch has been disarmed
+    pub fn trigger_time(&self) -> Option<DateTime<Utc>> {
+        self.trigger_time
+    }
+
+    pub fn is_armed(&self) -> bool {
+        self.trigger_time.is_some()
+    }
+}
+
+pub fn new(
+    current_time: DateTime<Utc>,
+    trigger_time: Option<DateTime<Utc>>,
+) -> Result<DeadManSwitch, ValidationError> {
+    let _ = validate_trigger_time(current_time, trigger_time)?;
+    Ok(DeadManSwitch {
+        current_time: current_time,
+        trigger_time: trigger_time,
+    })
+}
+
+fn validate_trigger_time(
+    current_time: DateTime<Utc>,
+    trigger_time: Option<DateTime<Utc>>,
+) -> Result<(), ValidationError> {
+    match trigger_time {
+        Some(trigger_time) if trigger_time < current_time => Err(validation_error::new(
+            "the trigger time cannot be before the current time",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone, Utc};
+
+    use super::new;
+
+    #[test]
+    fn should_new_return_error_if_the_trigger_time_is_in_the_past() {
+        //Arrange
+        let current_time = Utc.timestamp_opt(1688666559, 0).unwrap();
+        let trigger_time = current_time - Duration::seconds(60);
+
+        //Act
+        let result = new(current_time, Some(trigger_time));
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_be_disarmed_without_a_trigger_time() {
+        //Arrange
+        let current_time = Utc.timestamp_opt(1688666559, 0).unwrap();
+
+        //Act
+        let dead_man_switch = new(current_time, None).unwrap();
+
+        //Assert
+        assert!(!dead_man_switch.is_armed());
+    }
+}
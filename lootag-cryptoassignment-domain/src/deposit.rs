@@ -0,0 +1,292 @@
+use crate::{
+    currency_amount::CurrencyAmount,
+    funding_status::{FundingStatus, FundingStatusProperty},
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct DepositMethod {
+    method: String,
+    limit: Option<f32>,
+    fee: Option<CurrencyAmount>,
+    address_setup_fee: Option<CurrencyAmount>,
+    generates_address: bool,
+    minimum: Option<CurrencyAmount>,
+}
+
+#[derive(Debug)]
+pub struct DepositAddress {
+    address: String,
+    expire_time: Option<u64>,
+    new: bool,
+    tag: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Deposit {
+    method: String,
+    asset_class: String,
+    asset: String,
+    refid: String,
+    txid: String,
+    info: String,
+    amount: CurrencyAmount,
+    fee: CurrencyAmount,
+    time: u64,
+    status: FundingStatus,
+    status_property: Option<FundingStatusProperty>,
+}
+
+impl DepositMethod {
+    pub fn method(&self) -> &String {
+        &self.method
+    }
+
+    //None when the method has no deposit limit
+    pub fn limit(&self) -> Option<f32> {
+        self.limit
+    }
+
+    pub fn fee(&self) -> Option<&CurrencyAmount> {
+        self.fee.as_ref()
+    }
+
+    pub fn address_setup_fee(&self) -> Option<&CurrencyAmount> {
+        self.address_setup_fee.as_ref()
+    }
+
+    pub fn generates_address(&self) -> bool {
+        self.generates_address
+    }
+
+    pub fn minimum(&self) -> Option<&CurrencyAmount> {
+        self.minimum.as_ref()
+    }
+
+    pub fn accepts(&self, amount: f32) -> bool {
+        let above_minimum = match &self.minimum {
+            Some(minimum) => amount >= minimum.value(),
+            None => true,
+        };
+        let below_limit = match self.limit {
+            Some(limit) => amount <= limit,
+            None => true,
+        };
+        above_minimum && below_limit
+    }
+}
+
+impl DepositAddress {
+    pub fn address(&self) -> &String {
+        &self.address
+    }
+
+    //None when the address never expires
+    pub fn expire_time(&self) -> Option<u64> {
+        self.expire_time
+    }
+
+    pub fn is_new(&self) -> bool {
+        self.new
+    }
+
+    pub fn tag(&self) -> Option<&String> {
+        self.tag.as_ref()
+    }
+}
+
+impl Deposit {
+    pub fn method(&self) -> &String {
+        &self.method
+    }
+
+    pub fn asset_class(&self) -> &String {
+        &self.asset_class
+    }
+
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn refid(&self) -> &String {
+        &self.refid
+    }
+
+    pub fn txid(&self) -> &String {
+        &self.txid
+    }
+
+    pub fn info(&self) -> &String {
+        &self.info
+    }
+
+    pub fn amount(&self) -> &CurrencyAmount {
+        &self.amount
+    }
+
+    pub fn fee(&self) -> &CurrencyAmount {
+        &self.fee
+    }
+
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    pub fn status(&self) -> FundingStatus {
+        self.status
+    }
+
+    pub fn status_property(&self) -> Option<FundingStatusProperty> {
+        self.status_property
+    }
+}
+
+pub fn new_method(
+    method: String,
+    limit: Option<f32>,
+    fee: Option<CurrencyAmount>,
+    address_setup_fee: Option<CurrencyAmount>,
+    generates_address: bool,
+    minimum: Option<CurrencyAmount>,
+) -> Result<DepositMethod, ValidationError> {
+    let _ = validate_limit(limit, minimum.as_ref())?;
+    Ok(DepositMethod {
+        method: method,
+        limit: limit,
+        fee: fee,
+        address_setup_fee: address_setup_fee,
+        generates_address: generates_address,
+        minimum: minimum,
+    })
+}
+
+pub fn new_address(
+    address: String,
+    expire_time: Option<u64>,
+    new: bool,
+    tag: Option<String>,
+) -> Result<DepositAddress, ValidationError> {
+    let _ = validate_address(&address)?;
+    Ok(DepositAddress {
+        address: address,
+        expire_time: expire_time,
+        new: new,
+        tag: tag,
+    })
+}
+
+pub fn new(
+    method: String,
+    asset_class: String,
+    asset: String,
+    refid: String,
+    txid: String,
+    info: String,
+    amount: CurrencyAmount,
+    fee: CurrencyAmount,
+    time: u64,
+    status: FundingStatus,
+    status_property: Option<FundingStatusProperty>,
+) -> Result<Deposit, ValidationError> {
+    let _ = validate_refid(&refid)?;
+    Ok(Deposit {
+        method: method,
+        asset_class: asset_class,
+        asset: asset,
+        refid: refid,
+        txid: txid,
+        info: info,
+        amount: amount,
+        fee: fee,
+        time: time,
+        status: status,
+        status_property: status_property,
+    })
+}
+
+fn validate_limit(
+    limit: Option<f32>,
+    minimum: Option<&CurrencyAmount>,
+) -> Result<(), ValidationError> {
+    match (limit, minimum) {
+        (Some(limit), Some(minimum)) if limit < minimum.value() => Err(validation_error::new(
+            "a deposit limit cannot be below the minimum deposit",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_address(address: &String) -> Result<(), ValidationError> {
+    if address.is_empty() {
+        Err(validation_error::new("a deposit address cannot be empty"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_refid(refid: &String) -> Result<(), ValidationError> {
+    if refid.is_empty() {
+        Err(validation_error::new("a deposit needs a reference id"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_amount;
+
+    use super::{new_address, new_method};
+
+    #[test]
+    fn should_new_method_return_error_if_the_limit_is_below_the_minimum() {
+        //Arrange
+        let minimum = currency_amount::new(0.0001).unwrap();
+
+        //Act
+        let result = new_method(
+            String::from("Bitcoin"),
+            Some(0.00001),
+            None,
+            None,
+            true,
+            Some(minimum),
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_accepts_reject_amounts_below_the_minimum() {
+        //Arrange
+        let minimum = currency_amount::new(0.0001).unwrap();
+        let method = new_method(
+            String::from("Bitcoin"),
+            None,
+            None,
+            None,
+            true,
+            Some(minimum),
+        )
+        .unwrap();
+
+        //Act
+        let accepts = method.accepts(0.00005);
+
+        //Assert
+        assert!(!accepts);
+    }
+
+    #[test]
+    fn should_new_address_return_error_if_the_address_is_empty() {
+        //Arrange
+        let address = String::new();
+
+        //Act
+        let result = new_address(address, None, true, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,380 @@
+use crate::{
+    currency_amount::CurrencyAmount,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LockType {
+    Flex,
+    Bonded,
+    Timed,
+    Instant,
+}
+
+//Estimated yearly yield, in percent
+#[derive(Debug, Clone, Copy)]
+pub struct AprRange {
+    low: f32,
+    high: f32,
+}
+
+#[derive(Debug)]
+pub struct EarnStrategy {
+    id: String,
+    asset: String,
+    lock_type: LockType,
+    apr: Option<AprRange>,
+    minimum_allocation: Option<f64>,
+    can_allocate: bool,
+    can_deallocate: bool,
+}
+
+#[derive(Debug)]
+pub struct EarnAllocation {
+    strategy_id: String,
+    asset: String,
+    allocated: CurrencyAmount,
+    allocated_converted: CurrencyAmount,
+    rewarded: CurrencyAmount,
+    rewarded_converted: CurrencyAmount,
+}
+
+//The converted amounts are all expressed in the same converted asset
+#[derive(Debug)]
+pub struct EarnAllocations {
+    converted_asset: String,
+    total_allocated: CurrencyAmount,
+    total_rewarded: CurrencyAmount,
+    allocations: Vec<EarnAllocation>,
+}
+
+//An amount to allocate to or deallocate from a strategy
+#[derive(Debug, Clone)]
+pub struct EarnRequest {
+    strategy_id: String,
+    amount: f64,
+}
+
+impl LockType {
+    pub fn value(&self) -> &'static str {
+        match self {
+            LockType::Flex => "flex",
+            LockType::Bonded => "bonded",
+            LockType::Timed => "timed",
+            LockType::Instant => "instant",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<LockType, ValidationError> {
+        match value {
+            "flex" => Ok(LockType::Flex),
+            "bonded" => Ok(LockType::Bonded),
+            "timed" => Ok(LockType::Timed),
+            "instant" => Ok(LockType::Instant),
+            _ => Err(validation_error::new("unknown lock type")),
+        }
+    }
+}
+
+impl AprRange {
+    pub fn low(&self) -> f32 {
+        self.low
+    }
+
+    pub fn high(&self) -> f32 {
+        self.high
+    }
+}
+
+impl EarnStrategy {
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn lock_type(&self) -> LockType {
+        self.lock_type
+    }
+
+    //None when kraken doesn't publish an estimate for the strategy
+    pub fn apr(&self) -> Option<AprRange> {
+        self.apr
+    }
+
+    pub fn minimum_allocation(&self) -> Option<f64> {
+        self.minimum_allocation
+    }
+
+    pub fn can_allocate(&self) -> bool {
+        self.can_allocate
+    }
+
+    pub fn can_deallocate(&self) -> bool {
+        self.can_deallocate
+    }
+}
+
+impl EarnAllocation {
+    pub fn strategy_id(&self) -> &String {
+        &self.strategy_id
+    }
+
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn allocated(&self) -> &CurrencyAmount {
+        &self.allocated
+    }
+
+    pub fn allocated_converted(&self) -> &CurrencyAmount {
+        &self.allocated_converted
+    }
+
+    pub fn rewarded(&self) -> &CurrencyAmount {
+        &self.rewarded
+    }
+
+    pub fn rewarded_converted(&self) -> &CurrencyAmount {
+        &self.rewarded_converted
+    }
+}
+
+impl EarnAllocations {
+    pub fn converted_asset(&self) -> &String {
+        &self.converted_asset
+    }
+
+    pub fn total_allocated(&self) -> &CurrencyAmount {
+        &self.total_allocated
+    }
+
+    pub fn total_rewarded(&self) -> &CurrencyAmount {
+        &self.total_rewarded
+    }
+
+    pub fn allocations(&self) -> &Vec<EarnAllocation> {
+        &self.allocations
+    }
+
+    pub fn allocation(&self, strategy_id: &String) -> Option<&EarnAllocation> {
+        self.allocations
+            .iter()
+            .find(|allocation| &allocation.strategy_id == strategy_id)
+    }
+}
+
+impl EarnRequest {
+    pub fn strategy_id(&self) -> &String {
+        &self.strategy_id
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+}
+
+pub fn new_apr_range(low: f32, high: f32) -> Result<AprRange, ValidationError> {
+    if low < 0.0 || low > high {
+        Err(validation_error::new(
+            "an apr range needs a non negative low below its high",
+        ))
+    } else {
+        Ok(AprRange {
+            low: low,
+            high: high,
+        })
+    }
+}
+
+pub fn new_strategy(
+    id: String,
+    asset: String,
+    lock_type: LockType,
+    apr: Option<AprRange>,
+    minimum_allocation: Option<f64>,
+    can_allocate: bool,
+    can_deallocate: bool,
+) -> EarnStrategy {
+    EarnStrategy {
+        id: id,
+        asset: asset,
+        lock_type: lock_type,
+        apr: apr,
+        minimum_allocation: minimum_allocation,
+        can_allocate: can_allocate,
+        can_deallocate: can_deallocate,
+    }
+}
+
+pub fn new_allocation(
+    strategy_id: String,
+    asset: String,
+    allocated: CurrencyAmount,
+    allocated_converted: CurrencyAmount,
+    rewarded: CurrencyAmount,
+    rewarded_converted: CurrencyAmount,
+) -> EarnAllocation {
+    EarnAllocation {
+        strategy_id: strategy_id,
+        asset: asset,
+        allocated: allocated,
+        allocated_converted: allocated_converted,
+        rewarded: rewarded,
+        rewarded_converted: rewarded_converted,
+    }
+}
+
+pub fn new_allocations(
+    converted_asset: String,
+    total_allocated: CurrencyAmount,
+    total_rewarded: CurrencyAmount,
+    allocations: Vec<EarnAllocation>,
+) -> EarnAllocations {
+    EarnAllocations {
+        converted_asset: converted_asset,
+        total_allocated: total_allocated,
+        total_rewarded: total_rewarded,
+        allocations: allocations,
+    }
+}
+
+pub fn new_allocate_request(
+    strategy: &EarnStrategy,
+    amount: f64,
+) -> Result<EarnRequest, ValidationError> {
+    if !strategy.can_allocate {
+        return Err(validation_error::new(
+            "the strategy does not accept allocations",
+        ));
+    }
+    let _ = validate_amount(amount)?;
+    let _ = validate_minimum_allocation(amount, strategy)?;
+    Ok(EarnRequest {
+        strategy_id: strategy.id.clone(),
+        amount: amount,
+    })
+}
+
+pub fn new_deallocate_request(
+    strategy: &EarnStrategy,
+    amount: f64,
+) -> Result<EarnRequest, ValidationError> {
+    if !strategy.can_deallocate {
+        return Err(validation_error::new(
+            "the strategy does not allow deallocations",
+        ));
+    }
+    let _ = validate_amount(amount)?;
+    Ok(EarnRequest {
+        strategy_id: strategy.id.clone(),
+        amount: amount,
+    })
+}
+
+fn validate_amount(amount: f64) -> Result<(), ValidationError> {
+    if amount <= 0.0 {
+        Err(validation_error::new(
+            "an earn request needs a positive amount",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_minimum_allocation(
+    amount: f64,
+    strategy: &EarnStrategy,
+) -> Result<(), ValidationError> {
+    match strategy.minimum_allocation {
+        Some(minimum) if amount < minimum => Err(validation_error::new(&format!(
+            "the strategy needs an allocation of at least {}",
+            minimum
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        new_allocate_request, new_apr_range, new_deallocate_request, new_strategy, EarnStrategy,
+        LockType,
+    };
+
+    #[test]
+    fn should_new_apr_range_return_error_if_the_low_is_above_the_high() {
+        //Arrange
+        let low = 12.0;
+        let high = 8.0;
+
+        //Act
+        let result = new_apr_range(low, high);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_allocate_request_return_error_if_the_amount_is_below_the_minimum() {
+        //Arrange
+        let strategy = strategy(true, true);
+
+        //Act
+        let result = new_allocate_request(&strategy, 0.005);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_allocate_request_return_ok_if_the_amount_equals_the_minimum() {
+        //Arrange
+        let strategy = strategy(true, true);
+
+        //Act
+        let result = new_allocate_request(&strategy, 0.1);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_allocate_request_return_error_if_the_strategy_is_closed() {
+        //Arrange
+        let strategy = strategy(false, true);
+
+        //Act
+        let result = new_allocate_request(&strategy, 1.0);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_deallocate_request_ignore_the_minimum_allocation() {
+        //Arrange
+        let strategy = strategy(true, true);
+
+        //Act
+        let result = new_deallocate_request(&strategy, 0.005);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    fn strategy(can_allocate: bool, can_deallocate: bool) -> EarnStrategy {
+        new_strategy(
+            String::from("ESRFUO3-Q62XD-WIOIL7"),
+            String::from("DOT"),
+            LockType::Instant,
+            Some(new_apr_range(8.0, 12.0).unwrap()),
+            Some(0.1),
+            can_allocate,
+            can_deallocate,
+        )
+    }
+}
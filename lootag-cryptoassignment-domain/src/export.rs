@@ -0,0 +1,297 @@
+use crate::validation_error::{self, ValidationError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportType {
+    Trades,
+    Ledgers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Tsv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportStatus {
+    Queued,
+    Processing,
+    Processed,
+}
+
+//Queued or processing exports can only be cancelled, processed ones deleted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RemovalType {
+    Cancel,
+    Delete,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportRequest {
+    report: ReportType,
+    format: ExportFormat,
+    description: String,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+}
+
+#[derive(Debug)]
+pub struct Export {
+    id: String,
+    description: String,
+    report: ReportType,
+    format: ExportFormat,
+    status: ExportStatus,
+    created_time: u64,
+    completed_time: Option<u64>,
+    expire_time: u64,
+    start_time: u64,
+    end_time: u64,
+}
+
+impl ReportType {
+    pub fn value(&self) -> &'static str {
+        match self {
+            ReportType::Trades => "trades",
+            ReportType::Ledgers => "ledgers",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<ReportType, ValidationError> {
+        match value {
+            "trades" => Ok(ReportType::Trades),
+            "ledgers" => Ok(ReportType::Ledgers),
+            _ => Err(validation_error::new("unknown report type")),
+        }
+    }
+}
+
+impl ExportFormat {
+    pub fn value(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Tsv => "TSV",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<ExportFormat, ValidationError> {
+        match value {
+            "CSV" => Ok(ExportFormat::Csv),
+            "TSV" => Ok(ExportFormat::Tsv),
+            _ => Err(validation_error::new("unknown export format")),
+        }
+    }
+
+    pub fn delimiter(&self) -> u8 {
+        match self {
+            ExportFormat::Csv => b',',
+            ExportFormat::Tsv => b'\t',
+        }
+    }
+}
+
+impl ExportStatus {
+    pub fn value(&self) -> &'static str {
+        match self {
+            ExportStatus::Queued => "Queued",
+            ExportStatus::Processing => "Processing",
+            ExportStatus::Processed => "Processed",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<ExportStatus, ValidationError> {
+        match value {
+            "Queued" => Ok(ExportStatus::Queued),
+            "Processing" => Ok(ExportStatus::Processing),
+            "Processed" => Ok(ExportStatus::Processed),
+            _ => Err(validation_error::new("unknown export status")),
+        }
+    }
+}
+
+impl RemovalType {
+    pub fn value(&self) -> &'static str {
+        match self {
+            RemovalType::Cancel => "cancel",
+            RemovalType::Delete => "delete",
+        }
+    }
+}
+
+impl ExportRequest {
+    pub fn report(&self) -> ReportType {
+        self.report
+    }
+
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    //None exports from the start of the account history
+    pub fn start_time(&self) -> Option<u64> {
+        self.start_time
+    }
+
+    //None exports up to now
+    pub fn end_time(&self) -> Option<u64> {
+        self.end_time
+    }
+}
+
+impl Export {
+    pub fn id(&self) -> &String {
+        &self.id
+    }
+
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    pub fn report(&self) -> ReportType {
+        self.report
+    }
+
+    pub fn format(&self) -> ExportFormat {
+        self.format
+    }
+
+    pub fn status(&self) -> ExportStatus {
+        self.status
+    }
+
+    pub fn created_time(&self) -> u64 {
+        self.created_time
+    }
+
+    //None until the export has been processed
+    pub fn completed_time(&self) -> Option<u64> {
+        self.completed_time
+    }
+
+    pub fn expire_time(&self) -> u64 {
+        self.expire_time
+    }
+
+    pub fn start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    pub fn end_time(&self) -> u64 {
+        self.end_time
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status == ExportStatus::Processed
+    }
+}
+
+pub fn new_request(
+    report: ReportType,
+    format: ExportFormat,
+    description: String,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+) -> Result<ExportRequest, ValidationError> {
+    let _ = validate_description(&description)?;
+    let _ = validate_time_range(start_time, end_time)?;
+    Ok(ExportRequest {
+        report: report,
+        format: format,
+        description: description,
+        start_time: start_time,
+        end_time: end_time,
+    })
+}
+
+pub fn new(
+    id: String,
+    description: String,
+    report: ReportType,
+    format: ExportFormat,
+    status: ExportStatus,
+    created_time: u64,
+    completed_time: Option<u64>,
+    expire_time: u64,
+    start_time: u64,
+    end_time: u64,
+) -> Result<Export, ValidationError> {
+    let _ = validate_time_range(Some(start_time), Some(end_time))?;
+    Ok(Export {
+        id: id,
+        description: description,
+        report: report,
+        format: format,
+        status: status,
+        created_time: created_time,
+        completed_time: completed_time,
+        expire_time: expire_time,
+        start_time: start_time,
+        end_time: end_time,
+    })
+}
+
+fn validate_description(description: &String) -> Result<(), ValidationError> {
+    if description.is_empty() {
+        Err(validation_error::new("an export needs a description"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_time_range(
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+) -> Result<(), ValidationError> {
+    match (start_time, end_time) {
+        (Some(start_time), Some(end_time)) if start_time > end_time => Err(validation_error::new(
+            "an export cannot end before it starts",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_request, ExportFormat, ReportType};
+
+    #[test]
+    fn should_new_request_return_error_if_the_export_ends_before_it_starts() {
+        //Arrange
+        let start_time = Some(1688666559);
+        let end_time = Some(1672531200);
+
+        //Act
+        let result = new_request(
+            ReportType::Ledgers,
+            ExportFormat::Csv,
+            String::from("2023 ledgers"),
+            start_time,
+            end_time,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_request_return_error_if_the_description_is_empty() {
+        //Arrange
+        let description = String::new();
+
+        //Act
+        let result = new_request(
+            ReportType::Trades,
+            ExportFormat::Csv,
+            description,
+            None,
+            None,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
@@ -1,11 +1,53 @@
-use std::{collections::BTreeMap, io::ErrorKind};
+use std::collections::BTreeMap;
+
+use crate::validation_error::{self, ValidationError};
 
 #[derive(Debug)]
 pub struct FeeSet {
     pub(crate) value: BTreeMap<u32, f32>,
 }
 
-pub fn new(value: BTreeMap<u32, f32>) -> Result<FeeSet, std::io::Error> {
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeeTier {
+    volume: u32,
+    fee: f32,
+}
+
+impl FeeTier {
+    pub fn volume(&self) -> u32 {
+        self.volume
+    }
+
+    pub fn fee(&self) -> f32 {
+        self.fee
+    }
+}
+
+impl FeeSet {
+    //The tiers are keyed by the minimum 30 day volume needed to reach them
+    pub fn tier_for(&self, volume: f32) -> Option<FeeTier> {
+        self.value
+            .iter()
+            .rev()
+            .find(|(tier_volume, _)| **tier_volume as f32 <= volume)
+            .map(|(tier_volume, fee)| FeeTier {
+                volume: *tier_volume,
+                fee: *fee,
+            })
+    }
+
+    pub fn next_tier(&self, volume: f32) -> Option<FeeTier> {
+        self.value
+            .iter()
+            .find(|(tier_volume, _)| **tier_volume as f32 > volume)
+            .map(|(tier_volume, fee)| FeeTier {
+                volume: *tier_volume,
+                fee: *fee,
+            })
+    }
+}
+
+pub fn new(value: BTreeMap<u32, f32>) -> Result<FeeSet, ValidationError> {
     fn is_fee_pair_valid(fee1: f32, fee2: f32) -> bool {
         fee1 > fee2 && fee2 >= 0.00
     }
@@ -13,14 +55,13 @@ pub fn new(value: BTreeMap<u32, f32>) -> Result<FeeSet, std::io::Error> {
         .iter()
         .map(|(_, fee)| fee.clone())
         .collect::<Vec<f32>>();
-    let are_fees_valid = (0..fees_vec.len() - 1)
-        .into_iter()
-        .all(|idx| is_fee_pair_valid(fees_vec[idx], fees_vec[idx + 1]));
+    let are_fees_valid = fees_vec
+        .windows(2)
+        .all(|fees| is_fee_pair_valid(fees[0], fees[1]));
     if are_fees_valid {
         Ok(FeeSet { value: value })
     } else {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
+        Err(validation_error::new(
             "fees need to be positive and decreasing in quantity",
         ))
     }
@@ -30,7 +71,7 @@ pub fn new(value: BTreeMap<u32, f32>) -> Result<FeeSet, std::io::Error> {
 mod tests {
     use std::collections::BTreeMap;
 
-    use super::new;
+    use super::{new, FeeTier};
 
     #[test]
     fn should_new_return_okay_if_all_validation_criteria_are_satisfied() {
@@ -47,6 +88,18 @@ mod tests {
         assert!(fee_set_result.is_ok());
     }
 
+    #[test]
+    fn should_new_return_okay_if_fee_set_is_empty() {
+        //Arrange
+        let fee_set_value = BTreeMap::new();
+
+        //Act
+        let fee_set_result = new(fee_set_value);
+
+        //Assert
+        assert!(fee_set_result.is_ok());
+    }
+
     #[test]
     fn should_new_return_error_if_fees_are_not_decreasing_in_quantity() {
         //Arrange
@@ -76,4 +129,40 @@ mod tests {
         //Assert
         assert!(fee_set_result.is_err());
     }
+
+    #[test]
+    fn should_tier_for_return_highest_tier_reached_by_the_volume() {
+        //Arrange
+        let fee_set = new([(0, 0.26), (50000, 0.24), (100000, 0.22)]
+            .into_iter()
+            .collect::<BTreeMap<u32, f32>>())
+        .unwrap();
+
+        //Act
+        let tier = fee_set.tier_for(75000.0);
+
+        //Assert
+        assert_eq!(
+            tier,
+            Some(FeeTier {
+                volume: 50000,
+                fee: 0.24
+            })
+        );
+    }
+
+    #[test]
+    fn should_next_tier_return_none_once_the_last_tier_is_reached() {
+        //Arrange
+        let fee_set = new([(0, 0.26), (50000, 0.24), (100000, 0.22)]
+            .into_iter()
+            .collect::<BTreeMap<u32, f32>>())
+        .unwrap();
+
+        //Act
+        let tier = fee_set.next_tier(150000.0);
+
+        //Assert
+        assert_eq!(tier, None);
+    }
 }
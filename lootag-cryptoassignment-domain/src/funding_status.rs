@@ -0,0 +1,70 @@
+use crate::validation_error::{self, ValidationError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FundingStatus {
+    Initial,
+    Pending,
+    Settled,
+    Success,
+    Failure,
+}
+
+//Additional state kraken attaches to a deposit or withdrawal that's on its way
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FundingStatusProperty {
+    Return,
+    OnHold,
+    CancelPending,
+    Canceled,
+    CancelDenied,
+}
+
+impl FundingStatus {
+    pub fn value(&self) -> &'static str {
+        match self {
+            FundingStatus::Initial => "Initial",
+            FundingStatus::Pending => "Pending",
+            FundingStatus::Settled => "Settled",
+            FundingStatus::Success => "Success",
+            FundingStatus::Failure => "Failure",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<FundingStatus, ValidationError> {
+        match value {
+            "Initial" => Ok(FundingStatus::Initial),
+            "Pending" => Ok(FundingStatus::Pending),
+            "Settled" => Ok(FundingStatus::Settled),
+            "Success" => Ok(FundingStatus::Success),
+            "Failure" => Ok(FundingStatus::Failure),
+            _ => Err(validation_error::new("unknown funding status")),
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self, FundingStatus::Success | FundingStatus::Failure)
+    }
+}
+
+impl FundingStatusProperty {
+    pub fn value(&self) -> &'static str {
+        match self {
+            FundingStatusProperty::Return => "return",
+            FundingStatusProperty::OnHold => "onhold",
+            FundingStatusProperty::CancelPending => "cancel-pending",
+            FundingStatusProperty::Canceled => "canceled",
+            FundingStatusProperty::CancelDenied => "cancel-denied",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<FundingStatusProperty, ValidationError> {
+        match value {
+            "return" => Ok(FundingStatusProperty::Return),
+            "onhold" => Ok(FundingStatusProperty::OnHold),
+            "cancel-pending" => Ok(FundingStatusProperty::CancelPending),
+            "canceled" => Ok(FundingStatusProperty::Canceled),
+            "cancel-denied" => Ok(FundingStatusProperty::CancelDenied),
+            _ => Err(validation_error::new("unknown funding status property")),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use crate::validation_error::{self, ValidationError};
+
+#[derive(Debug)]
+pub struct LedgerEntry {
+    identifier: String,
+    refid: String,
+    time: f64,
+    entry_type: LedgerEntryType,
+    subtype: String,
+    asset_class: String,
+    asset: String,
+    amount: f64,
+    fee: f64,
+    balance: f64,
+}
+
+//Kraken adds entry types over time, so the ones this crate doesn't know yet are
+//kept as Other instead of failing the whole ledger
+#[derive(Debug, Clone, PartialEq)]
+pub enum LedgerEntryType {
+    None,
+    Trade,
+    Deposit,
+    Withdrawal,
+    Transfer,
+    Margin,
+    Adjustment,
+    Rollover,
+    Spend,
+    Receive,
+    Settled,
+    Credit,
+    Staking,
+    Reward,
+    Dividend,
+    Sale,
+    Conversion,
+    NftTrade,
+    NftCreatorFee,
+    NftRebate,
+    CustodyTransfer,
+    Earn,
+    Other(String),
+}
+
+impl LedgerEntryType {
+    pub fn value(&self) -> &str {
+        match self {
+            LedgerEntryType::None => "none",
+            LedgerEntryType::Trade => "trade",
+            LedgerEntryType::Deposit => "deposit",
+            LedgerEntryType::Withdrawal => "withdrawal",
+            LedgerEntryType::Transfer => "transfer",
+            LedgerEntryType::Margin => "margin",
+            LedgerEntryType::Adjustment => "adjustment",
+            LedgerEntryType::Rollover => "rollover",
+            LedgerEntryType::Spend => "spend",
+            LedgerEntryType::Receive => "receive",
+            LedgerEntryType::Settled => "settled",
+            LedgerEntryType::Credit => "credit",
+            LedgerEntryType::Staking => "staking",
+            LedgerEntryType::Reward => "reward",
+            LedgerEntryType::Dividend => "dividend",
+            LedgerEntryType::Sale => "sale",
+            LedgerEntryType::Conversion => "conversion",
+            LedgerEntryType::NftTrade => "nfttrade",
+            LedgerEntryType::NftCreatorFee => "nftcreatorfee",
+            LedgerEntryType::NftRebate => "nftrebate",
+            LedgerEntryType::CustodyTransfer => "custodytransfer",
+            LedgerEntryType::Earn => "earn",
+            LedgerEntryType::Other(value) => value,
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<LedgerEntryType, ValidationError> {
+        match value {
+            "none" => Ok(LedgerEntryType::None),
+            "trade" => Ok(LedgerEntryType::Trade),
+            "deposit" => Ok(LedgerEntryType::Deposit),
+            "withdrawal" => Ok(LedgerEntryType::Withdrawal),
+            "transfer" => Ok(LedgerEntryType::Transfer),
+            "margin" => Ok(LedgerEntryType::Margin),
+            "adjustment" => Ok(LedgerEntryType::Adjustment),
+            "rollover" => Ok(LedgerEntryType::Rollover),
+            "spend" => Ok(LedgerEntryType::Spend),
+            "receive" => Ok(LedgerEntryType::Receive),
+            "settled" => Ok(LedgerEntryType::Settled),
+            "credit" => Ok(LedgerEntryType::Credit),
+            "staking" => Ok(LedgerEntryType::Staking),
+            "reward" => Ok(LedgerEntryType::Reward),
+            "dividend" => Ok(LedgerEntryType::Dividend),
+            "sale" => Ok(LedgerEntryType::Sale),
+            "conversion" => Ok(LedgerEntryType::Conversion),
+            "nfttrade" => Ok(LedgerEntryType::NftTrade),
+            "nftcreatorfee" => Ok(LedgerEntryType::NftCreatorFee),
+            "nftrebate" => Ok(LedgerEntryType::NftRebate),
+            "custodytransfer" => Ok(LedgerEntryType::CustodyTransfer),
+            "earn" => Ok(LedgerEntryType::Earn),
+            "" => Err(validation_error::new("a ledger entry needs a type")),
+            _ => Ok(LedgerEntryType::Other(String::from(value))),
+        }
+    }
+}
+
+impl LedgerEntry {
+    pub fn identifier(&self) -> &String {
+        &self.identifier
+    }
+
+    pub fn refid(&self) -> &String {
+        &self.refid
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn entry_type(&self) -> &LedgerEntryType {
+        &self.entry_type
+    }
+
+    pub fn subtype(&self) -> &String {
+        &self.subtype
+    }
+
+    pub fn asset_class(&self) -> &String {
+        &self.asset_class
+    }
+
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn fee(&self) -> f64 {
+        self.fee
+    }
+
+    pub fn balance(&self) -> f64 {
+        self.balance
+    }
+}
+
+pub fn new(
+    identifier: String,
+    refid: String,
+    time: f64,
+    entry_type: LedgerEntryType,
+    subtype: String,
+    asset_class: String,
+    asset: String,
+    amount: f64,
+    fee: f64,
+    balance: f64,
+) -> Result<LedgerEntry, ValidationError> {
+    let _ = validate_asset(&asset)?;
+    let _ = validate_fee_and_balance(fee, balance)?;
+    Ok(LedgerEntry {
+        identifier: identifier,
+        refid: refid,
+        time: time,
+        entry_type: entry_type,
+        subtype: subtype,
+        asset_class: asset_class,
+        asset: asset,
+        amount: amount,
+        fee: fee,
+        balance: balance,
+    })
+}
+
+fn validate_asset(asset: &String) -> Result<(), ValidationError> {
+    if asset.is_empty() {
+        Err(validation_error::new("ledger entry asset cannot be empty"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_fee_and_balance(fee: f64, balance: f64) -> Result<(), ValidationError> {
+    if fee < 0.0 || balance < 0.0 {
+        Err(validation_error::new(
+            "ledger entry fee and balance cannot be negative",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new, LedgerEntryType};
+
+    #[test]
+    fn should_from_value_keep_unlisted_types_as_other() {
+        //Arrange
+        let value = "invite bonus";
+
+        //Act
+        let entry_type = LedgerEntryType::from_value(value).unwrap();
+
+        //Assert
+        assert_eq!(
+            entry_type,
+            LedgerEntryType::Other(String::from("invite bonus"))
+        );
+        assert_eq!(entry_type.value(), "invite bonus");
+    }
+
+    #[test]
+    fn should_from_value_return_earn_for_earn_entries() {
+        //Arrange
+        let value = "earn";
+
+        //Act
+        let entry_type = LedgerEntryType::from_value(value).unwrap();
+
+        //Assert
+        assert_eq!(entry_type, LedgerEntryType::Earn);
+    }
+
+    #[test]
+    fn should_new_return_ok_for_a_withdrawal_with_a_negative_amount() {
+        //Arrange
+        let amount = -0.5;
+
+        //Act
+        let result = new(
+            String::from("L4UESK-KG3EQ-UFO4T5"),
+            String::from("TJKLXX-PGMUI-4NTLXU"),
+            1688464484.1787,
+            LedgerEntryType::Withdrawal,
+            String::from(""),
+            String::from("currency"),
+            String::from("XXBT"),
+            amount,
+            0.0001,
+            1.2,
+        );
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_keep_every_decimal_of_the_balance() {
+        //Arrange
+        let balance = 1234.56789012;
+
+        //Act
+        let entry = new(
+            String::from("L4UESK-KG3EQ-UFO4T5"),
+            String::from("TJKLXX-PGMUI-4NTLXU"),
+            1688464484.1787,
+            LedgerEntryType::Deposit,
+            String::from(""),
+            String::from("currency"),
+            String::from("XXBT"),
+            0.5,
+            0.0,
+            balance,
+        )
+        .unwrap();
+
+        //Assert
+        assert_eq!(format!("{:.8}", entry.balance()), "1234.56789012");
+    }
+
+    #[test]
+    fn should_new_return_error_if_asset_is_empty() {
+        //Arrange
+        let asset = String::from("");
+
+        //Act
+        let result = new(
+            String::from("L4UESK-KG3EQ-UFO4T5"),
+            String::from("TJKLXX-PGMUI-4NTLXU"),
+            1688464484.1787,
+            LedgerEntryType::Deposit,
+            String::from(""),
+            String::from("currency"),
+            asset,
+            0.5,
+            0.0,
+            1.2,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_from_value_return_error_for_an_empty_type() {
+        //Arrange
+        let value = "";
+
+        //Act
+        let result = LedgerEntryType::from_value(value);
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
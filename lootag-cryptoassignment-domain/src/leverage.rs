@@ -1,11 +1,21 @@
-use std::io::ErrorKind;
+use crate::validation_error::{self, ValidationError};
 
 #[derive(Debug)]
 pub struct Leverage {
     pub(crate) value: Vec<u32>,
 }
 
-pub fn new(value: Vec<u32>) -> Result<Leverage, std::io::Error> {
+impl Leverage {
+    pub fn value(&self) -> &Vec<u32> {
+        &self.value
+    }
+
+    pub fn allows(&self, leverage: u32) -> bool {
+        self.value.contains(&leverage)
+    }
+}
+
+pub fn new(value: Vec<u32>) -> Result<Leverage, ValidationError> {
     let max_leverage_value = 5;
     let min_leverage_value = 1;
     let is_leverage_valid = value
@@ -14,8 +24,7 @@ pub fn new(value: Vec<u32>) -> Result<Leverage, std::io::Error> {
     if is_leverage_valid {
         Ok(Leverage { value: value })
     } else {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
+        Err(validation_error::new(
             "leverage needs to be between 1 and 5",
         ))
     }
@@ -1,9 +1,37 @@
+pub mod asset_pair;
+pub mod balance;
+pub mod candle;
+pub mod candle_series;
 pub mod credentials;
 pub mod currency_amount;
+pub mod dead_man_switch;
+pub mod deposit;
+pub mod earn;
+pub mod export;
 pub mod fee_set;
+pub mod funding_status;
+pub mod ledger_entry;
 pub mod leverage;
 pub mod margin;
+pub mod new_order;
 pub mod open_order;
 pub mod open_order_description;
+pub mod order_batch;
+pub mod order_book;
+pub mod order_cancellation;
+pub mod order_confirmation;
+pub mod order_edit;
+pub mod position;
+pub mod public_trade;
 pub mod server_time;
-pub mod xbt_usd;
+pub mod spread;
+pub mod subaccount;
+#[cfg(any(test, feature = "test-fixtures"))]
+pub mod test_fixtures;
+pub mod ticker;
+pub mod trade;
+pub mod trade_balance;
+pub mod trade_volume;
+pub mod transfer;
+pub mod validation_error;
+pub mod withdrawal;
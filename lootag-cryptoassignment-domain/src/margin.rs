@@ -1,4 +1,4 @@
-use std::io::ErrorKind;
+use crate::validation_error::{self, ValidationError};
 
 #[derive(Debug)]
 pub struct Margin {
@@ -11,13 +11,10 @@ impl Margin {
     }
 }
 
-pub fn new(value: u32) -> Result<Margin, std::io::Error> {
+pub fn new(value: u32) -> Result<Margin, ValidationError> {
     let maximimum_margin_value = 100;
     if value > maximimum_margin_value {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
-            "margin can't be larger than 100%",
-        ))
+        Err(validation_error::new("margin can't be larger than 100%"))
     } else {
         Ok(Margin { value: value })
     }
@@ -0,0 +1,692 @@
+use crate::{
+    asset_pair::AssetPair,
+    open_order_description::{OrderType, Position},
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug, Clone)]
+pub struct NewOrder {
+    pair: String,
+    position: Position,
+    order_type: OrderType,
+    volume: f64,
+    price: Option<f64>,
+    price2: Option<f64>,
+    leverage: Option<u32>,
+    oflags: Vec<OrderFlag>,
+    time_in_force: Option<TimeInForce>,
+    start_time: Option<ScheduledTime>,
+    expire_time: Option<ScheduledTime>,
+    userref: Option<i32>,
+    close: Option<CloseOrder>,
+    pair_decimals: usize,
+    lot_decimals: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewOrderBuilder {
+    pair: String,
+    position: Position,
+    order_type: OrderType,
+    volume: f64,
+    price: Option<f64>,
+    price2: Option<f64>,
+    leverage: Option<u32>,
+    oflags: Vec<OrderFlag>,
+    time_in_force: Option<TimeInForce>,
+    start_time: Option<ScheduledTime>,
+    expire_time: Option<ScheduledTime>,
+    userref: Option<i32>,
+    close: Option<CloseOrder>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CloseOrder {
+    order_type: OrderType,
+    price: f64,
+    price2: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrderFlag {
+    Post,
+    FeeInBase,
+    FeeInQuote,
+    NoMarketPriceProtection,
+    VolumeInQuote,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeInForce {
+    GoodTilCanceled,
+    ImmediateOrCancel,
+    GoodTilDate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScheduledTime {
+    Now,
+    At(u64),
+    In(u64),
+}
+
+impl OrderFlag {
+    pub fn value(&self) -> &'static str {
+        match self {
+            OrderFlag::Post => "post",
+            OrderFlag::FeeInBase => "fcib",
+            OrderFlag::FeeInQuote => "fciq",
+            OrderFlag::NoMarketPriceProtection => "nompp",
+            OrderFlag::VolumeInQuote => "viqc",
+        }
+    }
+}
+
+impl TimeInForce {
+    pub fn value(&self) -> &'static str {
+        match self {
+            TimeInForce::GoodTilCanceled => "GTC",
+            TimeInForce::ImmediateOrCancel => "IOC",
+            TimeInForce::GoodTilDate => "GTD",
+        }
+    }
+}
+
+impl ScheduledTime {
+    pub fn value(&self) -> String {
+        match self {
+            ScheduledTime::Now => String::from("0"),
+            ScheduledTime::At(unixtime) => unixtime.to_string(),
+            ScheduledTime::In(seconds) => format!("+{}", seconds),
+        }
+    }
+}
+
+impl CloseOrder {
+    pub fn order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    pub fn price2(&self) -> Option<f64> {
+        self.price2
+    }
+}
+
+impl NewOrder {
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    pub fn price(&self) -> Option<f64> {
+        self.price
+    }
+
+    pub fn price2(&self) -> Option<f64> {
+        self.price2
+    }
+
+    pub fn leverage(&self) -> Option<u32> {
+        self.leverage
+    }
+
+    pub fn oflags(&self) -> &Vec<OrderFlag> {
+        &self.oflags
+    }
+
+    pub fn time_in_force(&self) -> Option<TimeInForce> {
+        self.time_in_force
+    }
+
+    pub fn start_time(&self) -> Option<ScheduledTime> {
+        self.start_time
+    }
+
+    pub fn expire_time(&self) -> Option<ScheduledTime> {
+        self.expire_time
+    }
+
+    pub fn userref(&self) -> Option<i32> {
+        self.userref
+    }
+
+    pub fn close(&self) -> Option<&CloseOrder> {
+        self.close.as_ref()
+    }
+
+    //Amounts are rendered with the pair's own precision, since kraken rejects
+    //anything with more decimals than the pair allows
+    pub fn format_volume(&self) -> String {
+        format!("{:.*}", self.lot_decimals, self.volume)
+    }
+
+    pub fn format_price(&self, price: f64) -> String {
+        format!("{:.*}", self.pair_decimals, price)
+    }
+}
+
+pub fn builder(
+    pair: String,
+    position: Position,
+    order_type: OrderType,
+    volume: f64,
+) -> NewOrderBuilder {
+    NewOrderBuilder {
+        pair: pair,
+        position: position,
+        order_type: order_type,
+        volume: volume,
+        price: None,
+        price2: None,
+        leverage: None,
+        oflags: vec![],
+        time_in_force: None,
+        start_time: None,
+        expire_time: None,
+        userref: None,
+        close: None,
+    }
+}
+
+pub fn new_close_order(
+    order_type: OrderType,
+    price: f64,
+    price2: Option<f64>,
+) -> Result<CloseOrder, ValidationError> {
+    let _ = validate_close_order_type(order_type)?;
+    let _ = validate_secondary_price(order_type, price2)?;
+    Ok(CloseOrder {
+        order_type: order_type,
+        price: price,
+        price2: price2,
+    })
+}
+
+impl NewOrderBuilder {
+    pub fn price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn price2(mut self, price2: f64) -> Self {
+        self.price2 = Some(price2);
+        self
+    }
+
+    pub fn leverage(mut self, leverage: u32) -> Self {
+        self.leverage = Some(leverage);
+        self
+    }
+
+    pub fn oflag(mut self, oflag: OrderFlag) -> Self {
+        if !self.oflags.contains(&oflag) {
+            self.oflags.push(oflag);
+        }
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn start_time(mut self, start_time: ScheduledTime) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    pub fn expire_time(mut self, expire_time: ScheduledTime) -> Self {
+        self.expire_time = Some(expire_time);
+        self
+    }
+
+    pub fn userref(mut self, userref: i32) -> Self {
+        self.userref = Some(userref);
+        self
+    }
+
+    pub fn close(mut self, close: CloseOrder) -> Self {
+        self.close = Some(close);
+        self
+    }
+
+    //The pair has to be retrieved with AssetPairInfo::Info, which is the only
+    //variant that carries both the decimals and the leverage
+    pub fn build(self, asset_pair: &AssetPair) -> Result<NewOrder, ValidationError> {
+        let details = asset_pair.details().ok_or_else(|| {
+            validation_error::new("asset pair details are needed to place an order")
+        })?;
+        let _ = validate_pair(&self.pair, asset_pair)?;
+        let pair_decimals = details.pair_decimals() as usize;
+        let lot_decimals = details.lot_decimals() as usize;
+        let _ = validate_volume(self.volume, lot_decimals, details.order_min())?;
+        let _ = validate_prices(&self, pair_decimals)?;
+        let _ = validate_leverage(&self, asset_pair)?;
+        let _ = validate_oflags(&self)?;
+        let _ = validate_time_in_force(&self)?;
+        Ok(NewOrder {
+            pair: self.pair,
+            position: self.position,
+            order_type: self.order_type,
+            volume: self.volume,
+            price: self.price,
+            price2: self.price2,
+            leverage: self.leverage,
+            oflags: self.oflags,
+            time_in_force: self.time_in_force,
+            start_time: self.start_time,
+            expire_time: self.expire_time,
+            userref: self.userref,
+            close: self.close,
+            pair_decimals: pair_decimals,
+            lot_decimals: lot_decimals,
+        })
+    }
+}
+
+//Kraken accepts a pair by its name or its altname, the rules of any other pair
+//don't apply to the order
+fn validate_pair(pair: &String, asset_pair: &AssetPair) -> Result<(), ValidationError> {
+    let altname = asset_pair.details().map(|details| details.altname());
+    if pair == asset_pair.name() || Some(pair) == altname {
+        Ok(())
+    } else {
+        Err(validation_error::new(&format!(
+            "order pair {} does not match the asset pair {}",
+            pair,
+            asset_pair.name()
+        )))
+    }
+}
+
+//Counts the decimals of the shortest form that reads back as the same f64, since
+//scaling by a power of ten loses the last decimals of large amounts
+pub(crate) fn has_at_most_decimals(value: f64, decimals: usize) -> bool {
+    match value.to_string().split_once('.') {
+        Some((_, fraction)) => fraction.len() <= decimals,
+        None => true,
+    }
+}
+
+pub(crate) fn validate_volume(
+    volume: f64,
+    lot_decimals: usize,
+    order_min: &String,
+) -> Result<(), ValidationError> {
+    if volume <= 0.0 {
+        return Err(validation_error::new("order volume needs to be positive"));
+    }
+    if !has_at_most_decimals(volume, lot_decimals) {
+        return Err(validation_error::new(
+            "order volume has more decimals than the pair allows",
+        ));
+    }
+    let order_min = order_min
+        .parse::<f64>()
+        .map_err(|_| validation_error::new("the pair's minimum order is not a number"))?;
+    if volume < order_min {
+        Err(validation_error::new(
+            "order volume is below the pair's minimum order",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_prices(order: &NewOrderBuilder, pair_decimals: usize) -> Result<(), ValidationError> {
+    match (order.order_type.requires_price(), order.price) {
+        (true, None) => return Err(validation_error::new("order type needs a price")),
+        (false, Some(_)) => return Err(validation_error::new("order type does not take a price")),
+        _ => (),
+    }
+    let _ = validate_secondary_price(order.order_type, order.price2)?;
+    let close_prices = order
+        .close
+        .iter()
+        .flat_map(|close| vec![Some(close.price), close.price2]);
+    let all_prices_are_valid = vec![order.price, order.price2]
+        .into_iter()
+        .chain(close_prices)
+        .flatten()
+        .all(|price| price > 0.0 && has_at_most_decimals(price, pair_decimals));
+    if all_prices_are_valid {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "prices need to be positive and have at most the pair's decimals",
+        ))
+    }
+}
+
+fn validate_secondary_price(
+    order_type: OrderType,
+    price2: Option<f64>,
+) -> Result<(), ValidationError> {
+    match (order_type.requires_secondary_price(), price2) {
+        (true, None) => Err(validation_error::new("order type needs a secondary price")),
+        (false, Some(_)) => Err(validation_error::new(
+            "order type does not take a secondary price",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_close_order_type(order_type: OrderType) -> Result<(), ValidationError> {
+    match order_type {
+        OrderType::Limit
+        | OrderType::StopLoss
+        | OrderType::TakeProfit
+        | OrderType::StopLossLimit
+        | OrderType::TakeProfitLimit => Ok(()),
+        _ => Err(validation_error::new(
+            "order type cannot be used for a conditional close",
+        )),
+    }
+}
+
+fn validate_leverage(
+    order: &NewOrderBuilder,
+    asset_pair: &AssetPair,
+) -> Result<(), ValidationError> {
+    let leverage = match order.leverage {
+        Some(leverage) => leverage,
+        None if order.order_type == OrderType::SettlePosition => {
+            return Err(validation_error::new(
+                "settling a position needs the position's leverage",
+            ))
+        }
+        None => return Ok(()),
+    };
+    let pair_leverage = asset_pair.leverage().ok_or_else(|| {
+        validation_error::new("pair leverage is needed to place a leveraged order")
+    })?;
+    let allowed_leverage = match order.position {
+        Position::Buy => pair_leverage.leverage_buy(),
+        Position::Sell => pair_leverage.leverage_sell(),
+    };
+    if allowed_leverage.allows(leverage) {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "leverage is not available for the pair",
+        ))
+    }
+}
+
+fn validate_oflags(order: &NewOrderBuilder) -> Result<(), ValidationError> {
+    let has_flag = |flag: OrderFlag| order.oflags.contains(&flag);
+    if has_flag(OrderFlag::FeeInBase) && has_flag(OrderFlag::FeeInQuote) {
+        Err(validation_error::new(
+            "fees cannot be charged in both base and quote currency",
+        ))
+    } else if has_flag(OrderFlag::Post) && order.order_type != OrderType::Limit {
+        Err(validation_error::new("only limit orders can be post only"))
+    } else if has_flag(OrderFlag::VolumeInQuote) && order.order_type != OrderType::Market {
+        Err(validation_error::new(
+            "only market orders can have their volume in quote currency",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_time_in_force(order: &NewOrderBuilder) -> Result<(), ValidationError> {
+    match (order.time_in_force, order.expire_time) {
+        (Some(TimeInForce::GoodTilDate), None) => Err(validation_error::new(
+            "good til date orders need an expire time",
+        )),
+        (Some(TimeInForce::ImmediateOrCancel), _) if order.oflags.contains(&OrderFlag::Post) => {
+            Err(validation_error::new(
+                "immediate or cancel orders cannot be post only",
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        open_order_description::{OrderType, Position},
+        test_fixtures::asset_pair,
+    };
+
+    use super::{builder, new_close_order, OrderFlag, ScheduledTime, TimeInForce};
+
+    #[test]
+    fn should_build_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let asset_pair = asset_pair();
+        let close = new_close_order(OrderType::StopLoss, 28000.0, None).unwrap();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Buy,
+            OrderType::Limit,
+            1.25,
+        )
+        .price(30000.5)
+        .leverage(2)
+        .oflag(OrderFlag::Post)
+        .time_in_force(TimeInForce::GoodTilDate)
+        .expire_time(ScheduledTime::In(3600))
+        .close(close)
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_build_return_error_if_volume_has_more_decimals_than_the_lot() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Buy,
+            OrderType::Market,
+            0.123456789,
+        )
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_ok_if_a_large_volume_uses_every_decimal_of_the_lot() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Buy,
+            OrderType::Market,
+            1179.19201119,
+        )
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_build_return_error_if_the_order_is_for_another_pair() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XETHZUSD"),
+            Position::Buy,
+            OrderType::Market,
+            1.0,
+        )
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_ok_if_the_order_uses_the_altname_of_the_pair() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XBTUSD"),
+            Position::Buy,
+            OrderType::Market,
+            1.0,
+        )
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_build_return_error_if_volume_is_below_the_minimum_order() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Buy,
+            OrderType::Market,
+            0.00001,
+        )
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_error_if_price_has_more_decimals_than_the_pair() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Sell,
+            OrderType::Limit,
+            1.0,
+        )
+        .price(30000.25)
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_error_if_a_limit_order_has_no_price() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Sell,
+            OrderType::Limit,
+            1.0,
+        )
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_error_if_leverage_is_not_available_for_the_pair() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Sell,
+            OrderType::Market,
+            1.0,
+        )
+        .leverage(5)
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_error_if_a_market_order_is_post_only() {
+        //Arrange
+        let asset_pair = asset_pair();
+
+        //Act
+        let result = builder(
+            String::from("XXBTZUSD"),
+            Position::Buy,
+            OrderType::Market,
+            1.0,
+        )
+        .oflag(OrderFlag::Post)
+        .build(&asset_pair);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_close_order_return_error_for_a_market_close() {
+        //Arrange
+        let order_type = OrderType::Market;
+
+        //Act
+        let result = new_close_order(order_type, 28000.0, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_format_volume_use_the_lot_decimals() {
+        //Arrange
+        let asset_pair = asset_pair();
+        let order = builder(
+            String::from("XXBTZUSD"),
+            Position::Buy,
+            OrderType::Market,
+            1.25,
+        )
+        .build(&asset_pair)
+        .unwrap();
+
+        //Act
+        let volume = order.format_volume();
+
+        //Assert
+        assert_eq!(volume, String::from("1.25000000"));
+    }
+}
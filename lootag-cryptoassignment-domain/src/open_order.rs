@@ -1,6 +1,8 @@
-use std::io::ErrorKind;
-
-use crate::{currency_amount::CurrencyAmount, open_order_description::OpenOrderDescription};
+use crate::{
+    currency_amount::CurrencyAmount,
+    open_order_description::OpenOrderDescription,
+    validation_error::{self, ValidationError},
+};
 
 #[derive(Debug)]
 pub struct OpenOrder {
@@ -8,9 +10,10 @@ pub struct OpenOrder {
     refid: Option<String>,
     userref: u32,
     status: OrderStatus,
-    opentm: f32,
-    starttm: f32,
-    expiretm: f32,
+    opentm: f64,
+    starttm: f64,
+    expiretm: f64,
+    closetm: Option<f64>,
     description: OpenOrderDescription,
     volume: CurrencyAmount,
     vol_exec: CurrencyAmount,
@@ -22,12 +25,47 @@ pub struct OpenOrder {
     misc: String,
     oflags: String,
     trades: Vec<String>,
+    reason: Option<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OrderStatus {
+    Pending,
     Open,
     Closed,
+    Canceled,
+    Expired,
+}
+
+impl OrderStatus {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Closed | OrderStatus::Canceled | OrderStatus::Expired
+        )
+    }
+}
+
+impl OpenOrder {
+    pub fn identifier(&self) -> &String {
+        &self.identifier
+    }
+
+    pub fn status(&self) -> OrderStatus {
+        self.status
+    }
+
+    pub fn closetm(&self) -> Option<f64> {
+        self.closetm
+    }
+
+    pub fn trades(&self) -> &Vec<String> {
+        &self.trades
+    }
+
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
 }
 
 pub fn new(
@@ -35,9 +73,10 @@ pub fn new(
     refid: Option<String>,
     userref: u32,
     status: OrderStatus,
-    opentm: f32,
-    starttm: f32,
-    expiretm: f32,
+    opentm: f64,
+    starttm: f64,
+    expiretm: f64,
+    closetm: Option<f64>,
     description: OpenOrderDescription,
     volume: CurrencyAmount,
     vol_exec: CurrencyAmount,
@@ -49,8 +88,9 @@ pub fn new(
     misc: String,
     oflags: String,
     trades: Vec<String>,
-) -> Result<OpenOrder, std::io::Error> {
-    let _ = validate_order_status(&status);
+    reason: Option<String>,
+) -> Result<OpenOrder, ValidationError> {
+    let _ = validate_close_time(&status, &closetm)?;
     Ok(OpenOrder {
         identifier: identifier,
         refid: refid,
@@ -59,6 +99,7 @@ pub fn new(
         opentm: opentm,
         starttm: starttm,
         expiretm: expiretm,
+        closetm: closetm,
         description: description,
         volume: volume,
         vol_exec: vol_exec,
@@ -70,15 +111,105 @@ pub fn new(
         misc: misc,
         oflags: oflags,
         trades: trades,
+        reason: reason,
     })
 }
 
-fn validate_order_status(order_status: &OrderStatus) -> Result<(), std::io::Error> {
-    match order_status {
-        &OrderStatus::Open => Ok(()),
-        _ => Err(std::io::Error::new(
-            ErrorKind::InvalidData,
-            "order cannot be closed",
+fn validate_close_time(
+    order_status: &OrderStatus,
+    closetm: &Option<f64>,
+) -> Result<(), ValidationError> {
+    match (order_status.is_finished(), closetm) {
+        (true, None) => Err(validation_error::new("a finished order needs a close time")),
+        (false, Some(_)) => Err(validation_error::new(
+            "an order that is still active cannot have a close time",
         )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        currency_amount,
+        open_order_description::{self, OrderType, Position},
+        validation_error::ValidationError,
+    };
+
+    use super::{new, OpenOrder, OrderStatus};
+
+    #[test]
+    fn should_new_return_ok_if_a_canceled_order_has_a_close_time() {
+        //Arrange
+        let status = OrderStatus::Canceled;
+        let closetm = Some(1688671900.0);
+
+        //Act
+        let result = order(status, closetm);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_a_closed_order_has_no_close_time() {
+        //Arrange
+        let status = OrderStatus::Closed;
+        let closetm = None;
+
+        //Act
+        let result = order(status, closetm);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_a_pending_order_has_a_close_time() {
+        //Arrange
+        let status = OrderStatus::Pending;
+        let closetm = Some(1688671900.0);
+
+        //Act
+        let result = order(status, closetm);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn order(status: OrderStatus, closetm: Option<f64>) -> Result<OpenOrder, ValidationError> {
+        let description = open_order_description::new(
+            String::from("XBTUSD"),
+            Position::Buy,
+            OrderType::Limit,
+            30000.0,
+            0.0,
+            String::from("none"),
+            String::from("buy 1.25000000 XBTUSD @ limit 30000.0"),
+            String::from(""),
+        )
+        .unwrap();
+        new(
+            String::from("OQCLML-BW3P3-BUCMWZ"),
+            None,
+            0,
+            status,
+            1688671834.0,
+            0.0,
+            0.0,
+            closetm,
+            description,
+            currency_amount::new(1.25).unwrap(),
+            currency_amount::new(0.0).unwrap(),
+            currency_amount::new(0.0).unwrap(),
+            currency_amount::new(0.0).unwrap(),
+            currency_amount::new(0.0).unwrap(),
+            currency_amount::new(0.0).unwrap(),
+            currency_amount::new(0.0).unwrap(),
+            String::from(""),
+            String::from("fciq"),
+            vec![],
+            None,
+        )
     }
 }
@@ -1,3 +1,5 @@
+use crate::validation_error::ValidationError;
+
 #[derive(Debug)]
 pub struct OpenOrderDescription {
     pair: String,
@@ -10,16 +12,59 @@ pub struct OpenOrderDescription {
     close: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Position {
     Buy,
     Sell,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum OrderType {
     Market,
     Limit,
+    StopLoss,
+    TakeProfit,
+    StopLossLimit,
+    TakeProfitLimit,
+    TrailingStop,
+    TrailingStopLimit,
+    SettlePosition,
+}
+
+impl Position {
+    pub fn value(&self) -> &'static str {
+        match self {
+            Position::Buy => "buy",
+            Position::Sell => "sell",
+        }
+    }
+}
+
+impl OrderType {
+    pub fn value(&self) -> &'static str {
+        match self {
+            OrderType::Market => "market",
+            OrderType::Limit => "limit",
+            OrderType::StopLoss => "stop-loss",
+            OrderType::TakeProfit => "take-profit",
+            OrderType::StopLossLimit => "stop-loss-limit",
+            OrderType::TakeProfitLimit => "take-profit-limit",
+            OrderType::TrailingStop => "trailing-stop",
+            OrderType::TrailingStopLimit => "trailing-stop-limit",
+            OrderType::SettlePosition => "settle-position",
+        }
+    }
+
+    pub fn requires_price(&self) -> bool {
+        !matches!(self, OrderType::Market | OrderType::SettlePosition)
+    }
+
+    pub fn requires_secondary_price(&self) -> bool {
+        matches!(
+            self,
+            OrderType::StopLossLimit | OrderType::TakeProfitLimit | OrderType::TrailingStopLimit
+        )
+    }
 }
 
 pub fn new(
@@ -31,7 +76,7 @@ pub fn new(
     leverage: String,
     order: String,
     close: String,
-) -> Result<OpenOrderDescription, std::io::Error> {
+) -> Result<OpenOrderDescription, ValidationError> {
     Ok(OpenOrderDescription {
         pair: pair,
         position: position,
@@ -0,0 +1,257 @@
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+
+use crate::{
+    asset_pair::AssetPair,
+    new_order::{NewOrder, NewOrderBuilder},
+    validation_error::{self, ValidationError},
+};
+
+const MIN_BATCH_SIZE: usize = 2;
+const MAX_BATCH_SIZE: usize = 15;
+//Kraken rejects batches and edits whose deadline is less than 2 or more than 60
+//seconds away
+const MIN_DEADLINE_SECONDS: i64 = 2;
+const MAX_DEADLINE_SECONDS: i64 = 60;
+
+#[derive(Debug, Clone)]
+pub struct OrderBatch {
+    pair: String,
+    orders: Vec<NewOrder>,
+    deadline: Option<DateTime<Utc>>,
+}
+
+impl OrderBatch {
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn orders(&self) -> &Vec<NewOrder> {
+        &self.orders
+    }
+
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        self.deadline
+    }
+
+    pub fn format_deadline(&self) -> Option<String> {
+        self.deadline
+            .map(|deadline| deadline.to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+}
+
+//Every order is built against the batch pair, so a single invalid order rejects
+//the whole batch before anything is sent to the exchange
+pub fn new(
+    asset_pair: &AssetPair,
+    orders: Vec<NewOrderBuilder>,
+    deadline: Option<DateTime<Utc>>,
+) -> Result<OrderBatch, ValidationError> {
+    new_impl(asset_pair, orders, deadline, Utc::now)
+}
+
+fn new_impl(
+    asset_pair: &AssetPair,
+    orders: Vec<NewOrderBuilder>,
+    deadline: Option<DateTime<Utc>>,
+    now: fn() -> DateTime<Utc>,
+) -> Result<OrderBatch, ValidationError> {
+    let _ = validate_batch_size(&orders)?;
+    let _ = validate_deadline(deadline, now)?;
+    let orders = orders
+        .into_iter()
+        .enumerate()
+        .map(|(index, order)| build_order(asset_pair, index, order))
+        .collect::<Result<Vec<NewOrder>, ValidationError>>()?;
+    Ok(OrderBatch {
+        pair: asset_pair.name().clone(),
+        orders: orders,
+        deadline: deadline,
+    })
+}
+
+fn validate_batch_size(orders: &[NewOrderBuilder]) -> Result<(), ValidationError> {
+    if orders.len() < MIN_BATCH_SIZE || orders.len() > MAX_BATCH_SIZE {
+        Err(validation_error::new(&format!(
+            "a batch needs between {} and {} orders",
+            MIN_BATCH_SIZE, MAX_BATCH_SIZE
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn validate_deadline(
+    deadline: Option<DateTime<Utc>>,
+    now: fn() -> DateTime<Utc>,
+) -> Result<(), ValidationError> {
+    match deadline {
+        Some(deadline)
+            if deadline < now() + Duration::seconds(MIN_DEADLINE_SECONDS)
+                || deadline > now() + Duration::seconds(MAX_DEADLINE_SECONDS) =>
+        {
+            Err(validation_error::new(&format!(
+                "a deadline needs to be between {} and {} seconds from now",
+                MIN_DEADLINE_SECONDS, MAX_DEADLINE_SECONDS
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn build_order(
+    asset_pair: &AssetPair,
+    index: usize,
+    order: NewOrderBuilder,
+) -> Result<NewOrder, ValidationError> {
+    let order = order
+        .build(asset_pair)
+        .map_err(|e| validation_error::new(&format!("order {}: {}", index, e)))?;
+    if order.pair() != asset_pair.name() {
+        return Err(validation_error::new(&format!(
+            "order {}: every order in a batch needs to be for {}",
+            index,
+            asset_pair.name()
+        )));
+    }
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use crate::{
+        new_order::{self, NewOrderBuilder},
+        open_order_description::{OrderType, Position},
+        test_fixtures::asset_pair,
+    };
+
+    use super::{new, new_impl};
+
+    #[test]
+    fn should_new_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let orders = vec![
+            limit_order("XXBTZUSD", 30000.0),
+            limit_order("XXBTZUSD", 29900.0),
+        ];
+
+        //Act
+        let result = new(&asset_pair(), orders, None);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_there_is_a_single_order() {
+        //Arrange
+        let orders = vec![limit_order("XXBTZUSD", 30000.0)];
+
+        //Act
+        let result = new(&asset_pair(), orders, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_there_are_more_than_fifteen_orders() {
+        //Arrange
+        let orders = (0..16)
+            .map(|level| limit_order("XXBTZUSD", 30000.0 - level as f64))
+            .collect();
+
+        //Act
+        let result = new(&asset_pair(), orders, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_an_order_is_for_another_pair() {
+        //Arrange
+        let orders = vec![
+            limit_order("XXBTZUSD", 30000.0),
+            limit_order("XETHZUSD", 2000.0),
+        ];
+
+        //Act
+        let result = new(&asset_pair(), orders, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_an_order_breaks_the_pair_rules() {
+        //Arrange
+        let orders = vec![
+            limit_order("XXBTZUSD", 30000.0),
+            limit_order("XXBTZUSD", 29999.99),
+        ];
+
+        //Act
+        let result = new(&asset_pair(), orders, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_ok_if_the_deadline_is_within_a_minute() {
+        //Arrange
+        let orders = vec![
+            limit_order("XXBTZUSD", 30000.0),
+            limit_order("XXBTZUSD", 29900.0),
+        ];
+        let deadline = Utc.timestamp_opt(1688666589, 0).unwrap();
+
+        //Act
+        let result = new_impl(&asset_pair(), orders, Some(deadline), now);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_the_deadline_is_in_the_past() {
+        //Arrange
+        let orders = vec![
+            limit_order("XXBTZUSD", 30000.0),
+            limit_order("XXBTZUSD", 29900.0),
+        ];
+        let deadline = Utc.timestamp_opt(1688666549, 0).unwrap();
+
+        //Act
+        let result = new_impl(&asset_pair(), orders, Some(deadline), now);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_the_deadline_is_more_than_a_minute_away() {
+        //Arrange
+        let orders = vec![
+            limit_order("XXBTZUSD", 30000.0),
+            limit_order("XXBTZUSD", 29900.0),
+        ];
+        let deadline = Utc.timestamp_opt(1688666620, 0).unwrap();
+
+        //Act
+        let result = new_impl(&asset_pair(), orders, Some(deadline), now);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.timestamp_opt(1688666559, 0).unwrap()
+    }
+
+    fn limit_order(pair: &str, price: f64) -> NewOrderBuilder {
+        new_order::builder(String::from(pair), Position::Buy, OrderType::Limit, 0.5).price(price)
+    }
+}
@@ -0,0 +1,291 @@
+use std::cmp::Ordering;
+
+use is_sorted::IsSorted;
+
+use crate::{
+    currency_amount::CurrencyAmount,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct OrderBook {
+    pair: String,
+    bids: Vec<OrderBookLevel>,
+    asks: Vec<OrderBookLevel>,
+}
+
+#[derive(Debug)]
+pub struct OrderBookLevel {
+    price: CurrencyAmount,
+    volume: CurrencyAmount,
+    timestamp: u64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct DepthLevel {
+    price: f32,
+    cumulative_volume: f32,
+}
+
+impl OrderBook {
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn bids(&self) -> &Vec<OrderBookLevel> {
+        &self.bids
+    }
+
+    pub fn asks(&self) -> &Vec<OrderBookLevel> {
+        &self.asks
+    }
+
+    pub fn best_bid(&self) -> Option<&OrderBookLevel> {
+        self.bids.first()
+    }
+
+    pub fn best_ask(&self) -> Option<&OrderBookLevel> {
+        self.asks.first()
+    }
+
+    pub fn mid_price(&self) -> Option<f32> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some((bid.price.value() + ask.price.value()) / 2.0),
+            _ => None,
+        }
+    }
+
+    pub fn spread(&self) -> Option<f32> {
+        match (self.best_bid(), self.best_ask()) {
+            (Some(bid), Some(ask)) => Some(ask.price.value() - bid.price.value()),
+            _ => None,
+        }
+    }
+
+    pub fn bid_depth(&self, volume: f32) -> Vec<DepthLevel> {
+        cumulative_depth(&self.bids, volume)
+    }
+
+    pub fn ask_depth(&self, volume: f32) -> Vec<DepthLevel> {
+        cumulative_depth(&self.asks, volume)
+    }
+}
+
+impl OrderBookLevel {
+    pub fn price(&self) -> &CurrencyAmount {
+        &self.price
+    }
+
+    pub fn volume(&self) -> &CurrencyAmount {
+        &self.volume
+    }
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+impl DepthLevel {
+    pub fn price(&self) -> f32 {
+        self.price
+    }
+
+    pub fn cumulative_volume(&self) -> f32 {
+        self.cumulative_volume
+    }
+}
+
+pub fn new(
+    pair: String,
+    bids: Vec<OrderBookLevel>,
+    asks: Vec<OrderBookLevel>,
+) -> Result<OrderBook, ValidationError> {
+    let _ = validate_ladders_are_sorted(&bids, &asks)?;
+    let _ = validate_book_is_not_crossed(&bids, &asks)?;
+    Ok(OrderBook {
+        pair: pair,
+        bids: bids,
+        asks: asks,
+    })
+}
+
+pub fn new_level(
+    price: CurrencyAmount,
+    volume: CurrencyAmount,
+    timestamp: u64,
+) -> Result<OrderBookLevel, ValidationError> {
+    Ok(OrderBookLevel {
+        price: price,
+        volume: volume,
+        timestamp: timestamp,
+    })
+}
+
+//Walks the ladder from the top of the book until the requested volume is covered
+fn cumulative_depth(levels: &Vec<OrderBookLevel>, volume: f32) -> Vec<DepthLevel> {
+    let mut depth = Vec::new();
+    let mut cumulative_volume = 0.0;
+    for level in levels {
+        if cumulative_volume >= volume {
+            break;
+        }
+        cumulative_volume += level.volume.value();
+        depth.push(DepthLevel {
+            price: level.price.value(),
+            cumulative_volume: cumulative_volume,
+        });
+    }
+    depth
+}
+
+fn validate_ladders_are_sorted(
+    bids: &Vec<OrderBookLevel>,
+    asks: &Vec<OrderBookLevel>,
+) -> Result<(), ValidationError> {
+    fn compare_strictly(price1: &f32, price2: &f32) -> Option<Ordering> {
+        if price1 < price2 {
+            Some(Ordering::Less)
+        } else {
+            None
+        }
+    }
+    let are_bids_sorted = IsSorted::is_sorted_by(
+        &mut bids.iter().rev().map(|b| b.price.value()),
+        compare_strictly,
+    );
+    let are_asks_sorted =
+        IsSorted::is_sorted_by(&mut asks.iter().map(|a| a.price.value()), compare_strictly);
+    if are_bids_sorted && are_asks_sorted {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "bids need to be in decreasing and asks in increasing price order",
+        ))
+    }
+}
+
+fn validate_book_is_not_crossed(
+    bids: &Vec<OrderBookLevel>,
+    asks: &Vec<OrderBookLevel>,
+) -> Result<(), ValidationError> {
+    match (bids.first(), asks.first()) {
+        (Some(bid), Some(ask)) if bid.price.value() >= ask.price.value() => Err(
+            validation_error::new("the best bid cannot be at or above the best ask"),
+        ),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_amount;
+
+    use super::{new, new_level, DepthLevel, OrderBookLevel};
+
+    #[test]
+    fn should_new_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let bids = vec![level(30297.0, 0.1), level(30296.0, 0.2)];
+        let asks = vec![level(30298.0, 0.1), level(30299.0, 0.2)];
+
+        //Act
+        let result = new(String::from("XXBTZUSD"), bids, asks);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_bids_are_not_decreasing() {
+        //Arrange
+        let bids = vec![level(30296.0, 0.1), level(30297.0, 0.2)];
+        let asks = vec![level(30298.0, 0.1), level(30299.0, 0.2)];
+
+        //Act
+        let result = new(String::from("XXBTZUSD"), bids, asks);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_asks_are_not_increasing() {
+        //Arrange
+        let bids = vec![level(30297.0, 0.1), level(30296.0, 0.2)];
+        let asks = vec![level(30299.0, 0.1), level(30298.0, 0.2)];
+
+        //Act
+        let result = new(String::from("XXBTZUSD"), bids, asks);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_book_is_crossed() {
+        //Arrange
+        let bids = vec![level(30298.0, 0.1), level(30296.0, 0.2)];
+        let asks = vec![level(30298.0, 0.1), level(30299.0, 0.2)];
+
+        //Act
+        let result = new(String::from("XXBTZUSD"), bids, asks);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_mid_price_and_spread_be_computed_from_the_top_of_the_book() {
+        //Arrange
+        let bids = vec![level(30296.0, 0.1)];
+        let asks = vec![level(30298.0, 0.1)];
+        let order_book = new(String::from("XXBTZUSD"), bids, asks).unwrap();
+
+        //Act
+        let mid_price = order_book.mid_price();
+        let spread = order_book.spread();
+
+        //Assert
+        assert_eq!(mid_price, Some(30297.0));
+        assert_eq!(spread, Some(2.0));
+    }
+
+    #[test]
+    fn should_ask_depth_stop_once_the_volume_is_covered() {
+        //Arrange
+        let bids = vec![level(30296.0, 0.1)];
+        let asks = vec![
+            level(30298.0, 0.5),
+            level(30299.0, 1.0),
+            level(30300.0, 1.0),
+        ];
+        let order_book = new(String::from("XXBTZUSD"), bids, asks).unwrap();
+
+        //Act
+        let depth = order_book.ask_depth(1.2);
+
+        //Assert
+        assert_eq!(
+            depth,
+            vec![
+                DepthLevel {
+                    price: 30298.0,
+                    cumulative_volume: 0.5
+                },
+                DepthLevel {
+                    price: 30299.0,
+                    cumulative_volume: 1.5
+                },
+            ]
+        );
+    }
+
+    fn level(price: f32, volume: f32) -> OrderBookLevel {
+        new_level(
+            currency_amount::new(price).unwrap(),
+            currency_amount::new(volume).unwrap(),
+            1688671659,
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{
+    order_edit::OrderReference,
+    validation_error::{self, ValidationError},
+};
+
+const MAX_BATCH_SIZE: usize = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderCancellation {
+    count: u32,
+    pending: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CancellationBatch {
+    references: Vec<OrderReference>,
+}
+
+impl OrderCancellation {
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    //Orders that were still being placed when the cancellation arrived are
+    //cancelled once they're booked, so they aren't in the count yet
+    pub fn pending(&self) -> bool {
+        self.pending
+    }
+}
+
+impl CancellationBatch {
+    pub fn references(&self) -> &Vec<OrderReference> {
+        &self.references
+    }
+}
+
+pub fn new(count: u32, pending: bool) -> OrderCancellation {
+    OrderCancellation {
+        count: count,
+        pending: pending,
+    }
+}
+
+pub fn new_batch(references: Vec<OrderReference>) -> Result<CancellationBatch, ValidationError> {
+    let _ = validate_batch_size(&references)?;
+    Ok(CancellationBatch {
+        references: references,
+    })
+}
+
+fn validate_batch_size(references: &[OrderReference]) -> Result<(), ValidationError> {
+    if references.is_empty() || references.len() > MAX_BATCH_SIZE {
+        Err(validation_error::new(&format!(
+            "a cancellation batch needs between 1 and {} orders",
+            MAX_BATCH_SIZE
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::order_edit::OrderReference;
+
+    use super::new_batch;
+
+    #[test]
+    fn should_new_batch_return_error_if_there_are_no_orders() {
+        //Arrange
+        let references = vec![];
+
+        //Act
+        let result = new_batch(references);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_batch_return_error_if_there_are_more_than_fifty_orders() {
+        //Arrange
+        let references = (0..51).map(OrderReference::Userref).collect();
+
+        //Act
+        let result = new_batch(references);
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use crate::validation_error::{self, ValidationError};
+
+#[derive(Debug)]
+pub struct OrderConfirmation {
+    description: String,
+    close_description: Option<String>,
+    txids: Vec<String>,
+    validated_only: bool,
+}
+
+impl OrderConfirmation {
+    pub fn description(&self) -> &String {
+        &self.description
+    }
+
+    pub fn close_description(&self) -> Option<&String> {
+        self.close_description.as_ref()
+    }
+
+    pub fn txids(&self) -> &Vec<String> {
+        &self.txids
+    }
+
+    pub fn validated_only(&self) -> bool {
+        self.validated_only
+    }
+}
+
+pub fn new(
+    description: String,
+    close_description: Option<String>,
+    txids: Vec<String>,
+) -> Result<OrderConfirmation, ValidationError> {
+    let _ = validate_txids(&txids)?;
+    Ok(OrderConfirmation {
+        description: description,
+        close_description: close_description,
+        txids: txids,
+        validated_only: false,
+    })
+}
+
+//A validate-only request is checked by kraken but never executed, so there's no txid to report
+pub fn new_validated(description: String, close_description: Option<String>) -> OrderConfirmation {
+    OrderConfirmation {
+        description: description,
+        close_description: close_description,
+        txids: vec![],
+        validated_only: true,
+    }
+}
+
+fn validate_txids(txids: &Vec<String>) -> Result<(), ValidationError> {
+    if txids.is_empty() {
+        Err(validation_error::new(
+            "a placed order needs at least one txid",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new, new_validated};
+
+    #[test]
+    fn should_new_return_error_if_there_are_no_txids() {
+        //Arrange
+        let txids = vec![];
+
+        //Act
+        let result = new(
+            String::from("buy 1.25000000 XBTUSD @ limit 30000.0"),
+            None,
+            txids,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_validated_have_no_txids() {
+        //Arrange
+        let description = String::from("buy 1.25000000 XBTUSD @ limit 30000.0");
+
+        //Act
+        let confirmation = new_validated(description, None);
+
+        //Assert
+        assert!(confirmation.txids().is_empty());
+        assert!(confirmation.validated_only());
+    }
+}
@@ -0,0 +1,454 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{
+    asset_pair::AssetPair,
+    new_order::{self, OrderFlag},
+    open_order_description::OrderType,
+    order_batch,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderReference {
+    Txid(String),
+    Userref(i32),
+}
+
+impl OrderReference {
+    pub fn value(&self) -> String {
+        match self {
+            OrderReference::Txid(txid) => txid.clone(),
+            OrderReference::Userref(userref) => userref.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderEdit {
+    reference: OrderReference,
+    pair: String,
+    order_type: OrderType,
+    volume: Option<f64>,
+    price: Option<f64>,
+    price2: Option<f64>,
+    oflags: Vec<OrderFlag>,
+    deadline: Option<DateTime<Utc>>,
+    pair_decimals: usize,
+    lot_decimals: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct OrderEditBuilder {
+    reference: OrderReference,
+    order_type: OrderType,
+    volume: Option<f64>,
+    price: Option<f64>,
+    price2: Option<f64>,
+    oflags: Vec<OrderFlag>,
+    deadline: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug)]
+pub struct OrderEditConfirmation {
+    description: Option<String>,
+    txid: Option<String>,
+    original_txid: Option<String>,
+    amend_id: Option<String>,
+    validated_only: bool,
+}
+
+impl OrderEdit {
+    pub fn reference(&self) -> &OrderReference {
+        &self.reference
+    }
+
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    pub fn volume(&self) -> Option<f64> {
+        self.volume
+    }
+
+    pub fn price(&self) -> Option<f64> {
+        self.price
+    }
+
+    pub fn price2(&self) -> Option<f64> {
+        self.price2
+    }
+
+    pub fn oflags(&self) -> &Vec<OrderFlag> {
+        &self.oflags
+    }
+
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        self.deadline
+    }
+
+    pub fn format_volume(&self, volume: f64) -> String {
+        format!("{:.*}", self.lot_decimals, volume)
+    }
+
+    pub fn format_price(&self, price: f64) -> String {
+        format!("{:.*}", self.pair_decimals, price)
+    }
+
+    pub fn format_deadline(&self) -> Option<String> {
+        self.deadline
+            .map(|deadline| deadline.to_rfc3339_opts(SecondsFormat::Millis, true))
+    }
+
+    //AmendOrder only identifies orders by txid and only knows about the post only flag
+    pub fn validate_amendable(&self) -> Result<(), ValidationError> {
+        if !matches!(self.reference, OrderReference::Txid(_)) {
+            Err(validation_error::new(
+                "only orders referenced by txid can be amended",
+            ))
+        } else if self.oflags.iter().any(|oflag| *oflag != OrderFlag::Post) {
+            Err(validation_error::new(
+                "post only is the only flag an amend can set",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl OrderEditConfirmation {
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+
+    pub fn txid(&self) -> Option<&String> {
+        self.txid.as_ref()
+    }
+
+    pub fn original_txid(&self) -> Option<&String> {
+        self.original_txid.as_ref()
+    }
+
+    pub fn amend_id(&self) -> Option<&String> {
+        self.amend_id.as_ref()
+    }
+
+    pub fn validated_only(&self) -> bool {
+        self.validated_only
+    }
+}
+
+//The order type is the one of the order being edited, it decides which prices can change
+pub fn builder(reference: OrderReference, order_type: OrderType) -> OrderEditBuilder {
+    OrderEditBuilder {
+        reference: reference,
+        order_type: order_type,
+        volume: None,
+        price: None,
+        price2: None,
+        oflags: vec![],
+        deadline: None,
+    }
+}
+
+//An edit cancels the original order and places a new one with its own txid
+pub fn new_edit_confirmation(
+    description: String,
+    txid: String,
+    original_txid: String,
+) -> Result<OrderEditConfirmation, ValidationError> {
+    let _ = validate_txid(&txid)?;
+    Ok(OrderEditConfirmation {
+        description: Some(description),
+        txid: Some(txid),
+        original_txid: Some(original_txid),
+        amend_id: None,
+        validated_only: false,
+    })
+}
+
+pub fn new_validated_edit_confirmation(
+    description: String,
+    original_txid: Option<String>,
+) -> OrderEditConfirmation {
+    OrderEditConfirmation {
+        description: Some(description),
+        txid: None,
+        original_txid: original_txid,
+        amend_id: None,
+        validated_only: true,
+    }
+}
+
+//An amend changes the order in place, so it keeps its txid
+pub fn new_amend_confirmation(
+    amend_id: String,
+    txid: String,
+) -> Result<OrderEditConfirmation, ValidationError> {
+    let _ = validate_txid(&amend_id)?;
+    Ok(OrderEditConfirmation {
+        description: None,
+        txid: Some(txid.clone()),
+        original_txid: Some(txid),
+        amend_id: Some(amend_id),
+        validated_only: false,
+    })
+}
+
+impl OrderEditBuilder {
+    pub fn volume(mut self, volume: f64) -> Self {
+        self.volume = Some(volume);
+        self
+    }
+
+    pub fn price(mut self, price: f64) -> Self {
+        self.price = Some(price);
+        self
+    }
+
+    pub fn price2(mut self, price2: f64) -> Self {
+        self.price2 = Some(price2);
+        self
+    }
+
+    pub fn oflag(mut self, oflag: OrderFlag) -> Self {
+        if !self.oflags.contains(&oflag) {
+            self.oflags.push(oflag);
+        }
+        self
+    }
+
+    pub fn deadline(mut self, deadline: DateTime<Utc>) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    pub fn build(self, asset_pair: &AssetPair) -> Result<OrderEdit, ValidationError> {
+        self.build_impl(asset_pair, Utc::now)
+    }
+
+    fn build_impl(
+        self,
+        asset_pair: &AssetPair,
+        now: fn() -> DateTime<Utc>,
+    ) -> Result<OrderEdit, ValidationError> {
+        let details = asset_pair.details().ok_or_else(|| {
+            validation_error::new("asset pair details are needed to edit an order")
+        })?;
+        let pair_decimals = details.pair_decimals() as usize;
+        let lot_decimals = details.lot_decimals() as usize;
+        let _ = validate_changes(&self)?;
+        if let Some(volume) = self.volume {
+            let _ = new_order::validate_volume(volume, lot_decimals, details.order_min())?;
+        }
+        let _ = validate_prices(&self, pair_decimals)?;
+        let _ = validate_oflags(&self)?;
+        let _ = order_batch::validate_deadline(self.deadline, now)?;
+        Ok(OrderEdit {
+            reference: self.reference,
+            pair: asset_pair.name().clone(),
+            order_type: self.order_type,
+            volume: self.volume,
+            price: self.price,
+            price2: self.price2,
+            oflags: self.oflags,
+            deadline: self.deadline,
+            pair_decimals: pair_decimals,
+            lot_decimals: lot_decimals,
+        })
+    }
+}
+
+fn validate_txid(txid: &String) -> Result<(), ValidationError> {
+    if txid.is_empty() {
+        Err(validation_error::new("an edited order needs an identifier"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_changes(edit: &OrderEditBuilder) -> Result<(), ValidationError> {
+    if edit.volume.is_none()
+        && edit.price.is_none()
+        && edit.price2.is_none()
+        && edit.oflags.is_empty()
+    {
+        Err(validation_error::new("an edit needs to change something"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_prices(edit: &OrderEditBuilder, pair_decimals: usize) -> Result<(), ValidationError> {
+    if edit.price.is_some() && !edit.order_type.requires_price() {
+        return Err(validation_error::new("order type does not take a price"));
+    }
+    if edit.price2.is_some() && !edit.order_type.requires_secondary_price() {
+        return Err(validation_error::new(
+            "order type does not take a secondary price",
+        ));
+    }
+    let all_prices_are_valid = vec![edit.price, edit.price2]
+        .into_iter()
+        .flatten()
+        .all(|price| price > 0.0 && new_order::has_at_most_decimals(price, pair_decimals));
+    if all_prices_are_valid {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "prices need to be positive and have at most the pair's decimals",
+        ))
+    }
+}
+
+fn validate_oflags(edit: &OrderEditBuilder) -> Result<(), ValidationError> {
+    let has_flag = |flag: OrderFlag| edit.oflags.contains(&flag);
+    if has_flag(OrderFlag::NoMarketPriceProtection) || has_flag(OrderFlag::VolumeInQuote) {
+        Err(validation_error::new(
+            "only post only and fee currency flags can be edited",
+        ))
+    } else if has_flag(OrderFlag::FeeInBase) && has_flag(OrderFlag::FeeInQuote) {
+        Err(validation_error::new(
+            "fees cannot be charged in both base and quote currency",
+        ))
+    } else if has_flag(OrderFlag::Post) && edit.order_type != OrderType::Limit {
+        Err(validation_error::new("only limit orders can be post only"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use crate::{
+        new_order::OrderFlag, open_order_description::OrderType, test_fixtures::asset_pair,
+    };
+
+    use super::{builder, new_amend_confirmation, OrderReference};
+
+    #[test]
+    fn should_build_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let reference = OrderReference::Txid(String::from("OHYO67-6LP66-HMQ437"));
+
+        //Act
+        let result = builder(reference, OrderType::Limit)
+            .volume(0.5)
+            .price(30000.5)
+            .oflag(OrderFlag::Post)
+            .build(&asset_pair());
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_build_return_ok_if_the_deadline_is_within_a_minute() {
+        //Arrange
+        let reference = OrderReference::Txid(String::from("OHYO67-6LP66-HMQ437"));
+        let deadline = Utc.timestamp_opt(1688666589, 0).unwrap();
+
+        //Act
+        let result = builder(reference, OrderType::Limit)
+            .price(30000.5)
+            .deadline(deadline)
+            .build_impl(&asset_pair(), now);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_build_return_error_if_the_deadline_is_in_the_past() {
+        //Arrange
+        let reference = OrderReference::Txid(String::from("OHYO67-6LP66-HMQ437"));
+        let deadline = Utc.timestamp_opt(1688666549, 0).unwrap();
+
+        //Act
+        let result = builder(reference, OrderType::Limit)
+            .price(30000.5)
+            .deadline(deadline)
+            .build_impl(&asset_pair(), now);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_error_if_nothing_changes() {
+        //Arrange
+        let reference = OrderReference::Userref(42);
+
+        //Act
+        let result = builder(reference, OrderType::Limit).build(&asset_pair());
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_error_if_the_order_type_does_not_take_a_secondary_price() {
+        //Arrange
+        let reference = OrderReference::Txid(String::from("OHYO67-6LP66-HMQ437"));
+
+        //Act
+        let result = builder(reference, OrderType::Limit)
+            .price2(29000.0)
+            .build(&asset_pair());
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_build_return_error_if_volume_is_below_the_minimum_order() {
+        //Arrange
+        let reference = OrderReference::Txid(String::from("OHYO67-6LP66-HMQ437"));
+
+        //Act
+        let result = builder(reference, OrderType::Limit)
+            .volume(0.00001)
+            .build(&asset_pair());
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_validate_amendable_return_error_if_the_order_is_referenced_by_userref() {
+        //Arrange
+        let edit = builder(OrderReference::Userref(42), OrderType::Limit)
+            .price(30000.0)
+            .build(&asset_pair())
+            .unwrap();
+
+        //Act
+        let result = edit.validate_amendable();
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_amend_confirmation_keep_the_original_txid() {
+        //Arrange
+        let txid = String::from("OHYO67-6LP66-HMQ437");
+
+        //Act
+        let confirmation =
+            new_amend_confirmation(String::from("TGS4UP-7V5LB-N5LAAZ"), txid.clone()).unwrap();
+
+        //Assert
+        assert_eq!(confirmation.txid(), Some(&txid));
+        assert_eq!(confirmation.original_txid(), Some(&txid));
+    }
+
+    fn now() -> DateTime<Utc> {
+        Utc.timestamp_opt(1688666559, 0).unwrap()
+    }
+}
@@ -0,0 +1,230 @@
+use crate::{
+    currency_amount::CurrencyAmount,
+    open_order_description::Position as Side,
+    validation_error::{self, ValidationError},
+};
+
+//A margin position, as opposed to open_order_description::Position which is
+//only the side of an order
+#[derive(Debug)]
+pub struct Position {
+    identifier: Option<String>,
+    order_txid: Option<String>,
+    pair: String,
+    side: Side,
+    cost: CurrencyAmount,
+    fee: CurrencyAmount,
+    volume: CurrencyAmount,
+    volume_closed: CurrencyAmount,
+    margin: CurrencyAmount,
+    value: Option<CurrencyAmount>,
+    net: Option<f32>,
+    rollover_time: Option<u64>,
+}
+
+impl Position {
+    //None for positions consolidated by market
+    pub fn identifier(&self) -> Option<&String> {
+        self.identifier.as_ref()
+    }
+
+    pub fn order_txid(&self) -> Option<&String> {
+        self.order_txid.as_ref()
+    }
+
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    pub fn cost(&self) -> &CurrencyAmount {
+        &self.cost
+    }
+
+    pub fn fee(&self) -> &CurrencyAmount {
+        &self.fee
+    }
+
+    pub fn volume(&self) -> &CurrencyAmount {
+        &self.volume
+    }
+
+    pub fn volume_closed(&self) -> &CurrencyAmount {
+        &self.volume_closed
+    }
+
+    pub fn margin(&self) -> &CurrencyAmount {
+        &self.margin
+    }
+
+    //Only reported when the calculations are requested
+    pub fn value(&self) -> Option<&CurrencyAmount> {
+        self.value.as_ref()
+    }
+
+    //Only reported when the calculations are requested
+    pub fn net(&self) -> Option<f32> {
+        self.net
+    }
+
+    pub fn rollover_time(&self) -> Option<u64> {
+        self.rollover_time
+    }
+
+    pub fn open_volume(&self) -> f32 {
+        self.volume.value() - self.volume_closed.value()
+    }
+
+    //The cost is prorated to the volume that's still open, fees are left out
+    //just like they are in the net kraken reports
+    pub fn unrealised_pnl(&self, mark_price: f32) -> f32 {
+        let open_volume = self.open_volume();
+        let open_cost = self.cost.value() * open_volume / self.volume.value();
+        let market_value = mark_price * open_volume;
+        match self.side {
+            Side::Buy => market_value - open_cost,
+            Side::Sell => open_cost - market_value,
+        }
+    }
+
+    pub fn net_difference(&self, mark_price: f32) -> Option<f32> {
+        self.net.map(|net| net - self.unrealised_pnl(mark_price))
+    }
+}
+
+pub fn new(
+    identifier: Option<String>,
+    order_txid: Option<String>,
+    pair: String,
+    side: Side,
+    cost: CurrencyAmount,
+    fee: CurrencyAmount,
+    volume: CurrencyAmount,
+    volume_closed: CurrencyAmount,
+    margin: CurrencyAmount,
+    value: Option<CurrencyAmount>,
+    net: Option<f32>,
+    rollover_time: Option<u64>,
+) -> Result<Position, ValidationError> {
+    let _ = validate_volume(&volume, &volume_closed)?;
+    Ok(Position {
+        identifier: identifier,
+        order_txid: order_txid,
+        pair: pair,
+        side: side,
+        cost: cost,
+        fee: fee,
+        volume: volume,
+        volume_closed: volume_closed,
+        margin: margin,
+        value: value,
+        net: net,
+        rollover_time: rollover_time,
+    })
+}
+
+fn validate_volume(
+    volume: &CurrencyAmount,
+    volume_closed: &CurrencyAmount,
+) -> Result<(), ValidationError> {
+    if volume.value() <= 0.0 {
+        Err(validation_error::new("a position needs a positive volume"))
+    } else if volume_closed.value() > volume.value() {
+        Err(validation_error::new(
+            "a position cannot close more volume than it opened",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{currency_amount, open_order_description::Position as Side};
+
+    use super::{new, Position};
+
+    #[test]
+    fn should_new_return_error_if_closed_volume_exceeds_volume() {
+        //Arrange
+        let volume = currency_amount::new(1.0).unwrap();
+        let volume_closed = currency_amount::new(1.5).unwrap();
+
+        //Act
+        let result = new(
+            Some(String::from("TF5GVO-T7ZZ2-6NBKBI")),
+            Some(String::from("OJF2WU-RLDGC-D5P7RQ")),
+            String::from("XXBTZUSD"),
+            Side::Buy,
+            currency_amount::new(30000.0).unwrap(),
+            currency_amount::new(78.0).unwrap(),
+            volume,
+            volume_closed,
+            currency_amount::new(15000.0).unwrap(),
+            None,
+            None,
+            None,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_unrealised_pnl_only_count_the_open_volume_of_a_long() {
+        //Arrange
+        let position = position(Side::Buy, Some(1000.0));
+
+        //Act
+        let pnl = position.unrealised_pnl(32000.0);
+
+        //Assert
+        assert_eq!(pnl, 1000.0);
+    }
+
+    #[test]
+    fn should_unrealised_pnl_be_negative_for_a_short_when_the_price_rises() {
+        //Arrange
+        let position = position(Side::Sell, Some(-1000.0));
+
+        //Act
+        let pnl = position.unrealised_pnl(32000.0);
+
+        //Assert
+        assert_eq!(pnl, -1000.0);
+    }
+
+    #[test]
+    fn should_net_difference_be_zero_when_the_exchange_agrees() {
+        //Arrange
+        let position = position(Side::Buy, Some(1000.0));
+
+        //Act
+        let difference = position.net_difference(32000.0);
+
+        //Assert
+        assert_eq!(difference, Some(0.0));
+    }
+
+    //Opened 1 at 30000 and already closed half of it
+    fn position(side: Side, net: Option<f32>) -> Position {
+        new(
+            Some(String::from("TF5GVO-T7ZZ2-6NBKBI")),
+            Some(String::from("OJF2WU-RLDGC-D5P7RQ")),
+            String::from("XXBTZUSD"),
+            side,
+            currency_amount::new(30000.0).unwrap(),
+            currency_amount::new(78.0).unwrap(),
+            currency_amount::new(1.0).unwrap(),
+            currency_amount::new(0.5).unwrap(),
+            currency_amount::new(15000.0).unwrap(),
+            None,
+            net,
+            None,
+        )
+        .unwrap()
+    }
+}
@@ -0,0 +1,169 @@
+use is_sorted::IsSorted;
+
+use crate::{
+    currency_amount::CurrencyAmount,
+    open_order_description::{OrderType, Position},
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct PublicTrade {
+    price: CurrencyAmount,
+    volume: CurrencyAmount,
+    time: f64,
+    position: Position,
+    order_type: OrderType,
+    misc: String,
+    trade_id: u64,
+}
+
+#[derive(Debug)]
+pub struct PublicTradeSeries {
+    pair: String,
+    trades: Vec<PublicTrade>,
+    last: u64,
+}
+
+impl PublicTrade {
+    pub fn price(&self) -> &CurrencyAmount {
+        &self.price
+    }
+
+    pub fn volume(&self) -> &CurrencyAmount {
+        &self.volume
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn position(&self) -> &Position {
+        &self.position
+    }
+
+    pub fn order_type(&self) -> &OrderType {
+        &self.order_type
+    }
+
+    pub fn misc(&self) -> &String {
+        &self.misc
+    }
+
+    pub fn trade_id(&self) -> u64 {
+        self.trade_id
+    }
+}
+
+impl PublicTradeSeries {
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn trades(&self) -> &Vec<PublicTrade> {
+        &self.trades
+    }
+
+    pub fn last(&self) -> u64 {
+        self.last
+    }
+
+    pub fn into_trades(self) -> Vec<PublicTrade> {
+        self.trades
+    }
+}
+
+pub fn new(
+    price: CurrencyAmount,
+    volume: CurrencyAmount,
+    time: f64,
+    position: Position,
+    order_type: OrderType,
+    misc: String,
+    trade_id: u64,
+) -> Result<PublicTrade, ValidationError> {
+    Ok(PublicTrade {
+        price: price,
+        volume: volume,
+        time: time,
+        position: position,
+        order_type: order_type,
+        misc: misc,
+        trade_id: trade_id,
+    })
+}
+
+pub fn new_series(
+    pair: String,
+    trades: Vec<PublicTrade>,
+    last: u64,
+) -> Result<PublicTradeSeries, ValidationError> {
+    let _ = validate_trades_are_in_chronological_order(&trades)?;
+    Ok(PublicTradeSeries {
+        pair: pair,
+        trades: trades,
+        last: last,
+    })
+}
+
+fn validate_trades_are_in_chronological_order(
+    trades: &Vec<PublicTrade>,
+) -> Result<(), ValidationError> {
+    if IsSorted::is_sorted(&mut trades.iter().map(|t| t.time)) {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "trades need to be in chronological order",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        currency_amount,
+        open_order_description::{OrderType, Position},
+    };
+
+    use super::{new, new_series, PublicTrade};
+
+    #[test]
+    fn should_new_series_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let trades = vec![
+            trade(1688669597.8277),
+            trade(1688669597.8277),
+            trade(1688669598.1023),
+        ];
+
+        //Act
+        let result = new_series(String::from("XXBTZUSD"), trades, 1688669598102300000);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_series_return_error_if_trades_are_not_in_chronological_order() {
+        //Arrange
+        let trades = vec![trade(1688669598.1023), trade(1688669597.8277)];
+
+        //Act
+        let result = new_series(String::from("XXBTZUSD"), trades, 1688669598102300000);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn trade(time: f64) -> PublicTrade {
+        new(
+            currency_amount::new(30243.4).unwrap(),
+            currency_amount::new(0.345).unwrap(),
+            time,
+            Position::Buy,
+            OrderType::Market,
+            String::from(""),
+            61044952,
+        )
+        .unwrap()
+    }
+}
@@ -1,14 +1,14 @@
-use std::io::ErrorKind;
-
 use chrono::{DateTime, Utc};
 
+use crate::validation_error::{self, ValidationError};
+
 #[derive(Debug)]
 pub struct ServerTime {
     unixtime: u64,
     rfc1123: String,
 }
 
-pub fn new(unixtime: u64, rfc1123: String) -> Result<ServerTime, std::io::Error> {
+pub fn new(unixtime: u64, rfc1123: String) -> Result<ServerTime, ValidationError> {
     new_impl(unixtime, rfc1123, now)
 }
 
@@ -16,7 +16,7 @@ fn new_impl(
     unixtime: u64,
     rfc1123: String,
     now: fn() -> u64,
-) -> Result<ServerTime, std::io::Error> {
+) -> Result<ServerTime, ValidationError> {
     let _ = validate_unix_time_rfc_equivalence(unixtime, &rfc1123)?;
     let _ = validate_unix_time_is_not_in_the_future(unixtime, now)?;
     let _ = validate_unix_time_is_not_older_than_ten_seconds(unixtime, now)?;
@@ -33,26 +33,22 @@ fn now() -> u64 {
 fn validate_unix_time_rfc_equivalence(
     unixtime: u64,
     rfc1123: &String,
-) -> Result<(), std::io::Error> {
-    let dt = DateTime::parse_from_rfc2822(rfc1123)
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+) -> Result<(), ValidationError> {
+    let dt =
+        DateTime::parse_from_rfc2822(rfc1123).map_err(|e| validation_error::new(&e.to_string()))?;
     if dt.timestamp() as u64 == unixtime {
         Ok(())
     } else {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
-            "unix time and rfc1123 do not match!",
-        ))
+        Err(validation_error::new("unix time and rfc1123 do not match!"))
     }
 }
 
 fn validate_unix_time_is_not_in_the_future(
     unixtime: u64,
     now: fn() -> u64,
-) -> Result<(), std::io::Error> {
+) -> Result<(), ValidationError> {
     if unixtime > now() {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
+        Err(validation_error::new(
             "the api returned a timestamp from the future",
         ))
     } else {
@@ -63,11 +59,10 @@ fn validate_unix_time_is_not_in_the_future(
 fn validate_unix_time_is_not_older_than_ten_seconds(
     unixtime: u64,
     now: fn() -> u64,
-) -> Result<(), std::io::Error> {
+) -> Result<(), ValidationError> {
     let maximum_tolerance_in_seconds = 10;
     if unixtime < now() - maximum_tolerance_in_seconds {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
+        Err(validation_error::new(
             "the api returned a timestamp which is too old",
         ))
     } else {
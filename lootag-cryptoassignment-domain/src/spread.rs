@@ -0,0 +1,150 @@
+use is_sorted::IsSorted;
+
+use crate::{
+    currency_amount::CurrencyAmount,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct Spread {
+    time: u64,
+    bid: CurrencyAmount,
+    ask: CurrencyAmount,
+}
+
+#[derive(Debug)]
+pub struct SpreadSeries {
+    pair: String,
+    spreads: Vec<Spread>,
+    last: u64,
+}
+
+impl Spread {
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    pub fn bid(&self) -> &CurrencyAmount {
+        &self.bid
+    }
+
+    pub fn ask(&self) -> &CurrencyAmount {
+        &self.ask
+    }
+}
+
+impl SpreadSeries {
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn spreads(&self) -> &Vec<Spread> {
+        &self.spreads
+    }
+
+    pub fn last(&self) -> u64 {
+        self.last
+    }
+
+    pub fn into_spreads(self) -> Vec<Spread> {
+        self.spreads
+    }
+}
+
+pub fn new(time: u64, bid: CurrencyAmount, ask: CurrencyAmount) -> Result<Spread, ValidationError> {
+    let _ = validate_ask_is_not_below_bid(&bid, &ask)?;
+    Ok(Spread {
+        time: time,
+        bid: bid,
+        ask: ask,
+    })
+}
+
+pub fn new_series(
+    pair: String,
+    spreads: Vec<Spread>,
+    last: u64,
+) -> Result<SpreadSeries, ValidationError> {
+    let _ = validate_spreads_are_in_chronological_order(&spreads)?;
+    Ok(SpreadSeries {
+        pair: pair,
+        spreads: spreads,
+        last: last,
+    })
+}
+
+fn validate_ask_is_not_below_bid(
+    bid: &CurrencyAmount,
+    ask: &CurrencyAmount,
+) -> Result<(), ValidationError> {
+    if ask.value() < bid.value() {
+        Err(validation_error::new("ask price cannot be below bid price"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_spreads_are_in_chronological_order(
+    spreads: &Vec<Spread>,
+) -> Result<(), ValidationError> {
+    if IsSorted::is_sorted(&mut spreads.iter().map(|s| s.time)) {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "spreads need to be in chronological order",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_amount;
+
+    use super::{new, new_series, Spread};
+
+    #[test]
+    fn should_new_return_error_if_ask_is_below_bid() {
+        //Arrange
+        let bid = currency_amount::new(30297.5).unwrap();
+        let ask = currency_amount::new(30292.1).unwrap();
+
+        //Act
+        let result = new(1688671834, bid, ask);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_series_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let spreads = vec![spread(1688671834), spread(1688671834), spread(1688671835)];
+
+        //Act
+        let result = new_series(String::from("XXBTZUSD"), spreads, 1688671835);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_series_return_error_if_spreads_are_not_in_chronological_order() {
+        //Arrange
+        let spreads = vec![spread(1688671835), spread(1688671834)];
+
+        //Act
+        let result = new_series(String::from("XXBTZUSD"), spreads, 1688671835);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn spread(time: u64) -> Spread {
+        new(
+            time,
+            currency_amount::new(30292.1).unwrap(),
+            currency_amount::new(30297.5).unwrap(),
+        )
+        .unwrap()
+    }
+}
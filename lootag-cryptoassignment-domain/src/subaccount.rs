@@ -0,0 +1,58 @@
+use crate::validation_error::{self, ValidationError};
+
+#[derive(Debug, Clone)]
+pub struct Subaccount {
+    username: String,
+    email: String,
+}
+
+impl Subaccount {
+    pub fn username(&self) -> &String {
+        &self.username
+    }
+
+    pub fn email(&self) -> &String {
+        &self.email
+    }
+}
+
+pub fn new(username: String, email: String) -> Result<Subaccount, ValidationError> {
+    let _ = validate_username(&username)?;
+    let _ = validate_email(&email)?;
+    Ok(Subaccount {
+        username: username,
+        email: email,
+    })
+}
+
+fn validate_username(username: &String) -> Result<(), ValidationError> {
+    if username.is_empty() {
+        Err(validation_error::new("a subaccount needs a username"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_email(email: &String) -> Result<(), ValidationError> {
+    match email.split_once('@') {
+        Some((local, domain)) if !local.is_empty() && !domain.is_empty() => Ok(()),
+        _ => Err(validation_error::new("a subaccount needs a valid email")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::new;
+
+    #[test]
+    fn should_new_return_error_if_the_email_has_no_domain() {
+        //Arrange
+        let email = String::from("strategy-one@");
+
+        //Act
+        let result = new(String::from("strategy-one"), email);
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    asset_pair::{self, AssetPair},
+    leverage,
+};
+
+//XBT/USD with a price precision of one decimal, eight lot decimals and a minimum
+//order of 0.0001, which the order tests of this crate and of the services share
+pub fn asset_pair() -> AssetPair {
+    let details = asset_pair::new_details(
+        String::from("XBTUSD"),
+        String::from("XBT/USD"),
+        String::from("currency"),
+        String::from("XXBT"),
+        String::from("currency"),
+        String::from("ZUSD"),
+        String::from("unit"),
+        1,
+        8,
+        1,
+        String::from("0.0001"),
+    )
+    .unwrap();
+    let leverage = asset_pair::new_leverage(
+        leverage::new(vec![2, 3, 4, 5]).unwrap(),
+        leverage::new(vec![2, 3]).unwrap(),
+    )
+    .unwrap();
+    asset_pair::new(
+        String::from("XXBTZUSD"),
+        Some(details),
+        Some(leverage),
+        None,
+        None,
+    )
+    .unwrap()
+}
@@ -0,0 +1,293 @@
+use crate::{
+    currency_amount::CurrencyAmount,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct Ticker {
+    pair: String,
+    ask: TickerQuote,
+    bid: TickerQuote,
+    last_trade: TickerTrade,
+    volume: TickerWindow<CurrencyAmount>,
+    vwap: TickerWindow<CurrencyAmount>,
+    trade_count: TickerWindow<u32>,
+    low: TickerWindow<CurrencyAmount>,
+    high: TickerWindow<CurrencyAmount>,
+    open: CurrencyAmount,
+}
+
+#[derive(Debug)]
+pub struct TickerQuote {
+    price: CurrencyAmount,
+    whole_lot_volume: CurrencyAmount,
+    lot_volume: CurrencyAmount,
+}
+
+#[derive(Debug)]
+pub struct TickerTrade {
+    price: CurrencyAmount,
+    lot_volume: CurrencyAmount,
+}
+
+#[derive(Debug)]
+pub struct TickerWindow<T> {
+    today: T,
+    last_24_hours: T,
+}
+
+impl Ticker {
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn ask(&self) -> &TickerQuote {
+        &self.ask
+    }
+
+    pub fn bid(&self) -> &TickerQuote {
+        &self.bid
+    }
+
+    pub fn last_trade(&self) -> &TickerTrade {
+        &self.last_trade
+    }
+
+    pub fn volume(&self) -> &TickerWindow<CurrencyAmount> {
+        &self.volume
+    }
+
+    pub fn vwap(&self) -> &TickerWindow<CurrencyAmount> {
+        &self.vwap
+    }
+
+    pub fn trade_count(&self) -> &TickerWindow<u32> {
+        &self.trade_count
+    }
+
+    pub fn low(&self) -> &TickerWindow<CurrencyAmount> {
+        &self.low
+    }
+
+    pub fn high(&self) -> &TickerWindow<CurrencyAmount> {
+        &self.high
+    }
+
+    pub fn open(&self) -> &CurrencyAmount {
+        &self.open
+    }
+}
+
+impl TickerQuote {
+    pub fn price(&self) -> &CurrencyAmount {
+        &self.price
+    }
+
+    pub fn whole_lot_volume(&self) -> &CurrencyAmount {
+        &self.whole_lot_volume
+    }
+
+    pub fn lot_volume(&self) -> &CurrencyAmount {
+        &self.lot_volume
+    }
+}
+
+impl TickerTrade {
+    pub fn price(&self) -> &CurrencyAmount {
+        &self.price
+    }
+
+    pub fn lot_volume(&self) -> &CurrencyAmount {
+        &self.lot_volume
+    }
+}
+
+impl<T> TickerWindow<T> {
+    pub fn today(&self) -> &T {
+        &self.today
+    }
+
+    pub fn last_24_hours(&self) -> &T {
+        &self.last_24_hours
+    }
+}
+
+pub fn new(
+    pair: String,
+    ask: TickerQuote,
+    bid: TickerQuote,
+    last_trade: TickerTrade,
+    volume: TickerWindow<CurrencyAmount>,
+    vwap: TickerWindow<CurrencyAmount>,
+    trade_count: TickerWindow<u32>,
+    low: TickerWindow<CurrencyAmount>,
+    high: TickerWindow<CurrencyAmount>,
+    open: CurrencyAmount,
+) -> Result<Ticker, ValidationError> {
+    let _ = validate_ask_is_not_below_bid(&ask, &bid)?;
+    let _ = validate_low_is_not_above_high(&low, &high)?;
+    Ok(Ticker {
+        pair: pair,
+        ask: ask,
+        bid: bid,
+        last_trade: last_trade,
+        volume: volume,
+        vwap: vwap,
+        trade_count: trade_count,
+        low: low,
+        high: high,
+        open: open,
+    })
+}
+
+pub fn new_quote(
+    price: CurrencyAmount,
+    whole_lot_volume: CurrencyAmount,
+    lot_volume: CurrencyAmount,
+) -> Result<TickerQuote, ValidationError> {
+    Ok(TickerQuote {
+        price: price,
+        whole_lot_volume: whole_lot_volume,
+        lot_volume: lot_volume,
+    })
+}
+
+pub fn new_trade(
+    price: CurrencyAmount,
+    lot_volume: CurrencyAmount,
+) -> Result<TickerTrade, ValidationError> {
+    Ok(TickerTrade {
+        price: price,
+        lot_volume: lot_volume,
+    })
+}
+
+pub fn new_window<T>(today: T, last_24_hours: T) -> Result<TickerWindow<T>, ValidationError> {
+    Ok(TickerWindow {
+        today: today,
+        last_24_hours: last_24_hours,
+    })
+}
+
+fn validate_ask_is_not_below_bid(
+    ask: &TickerQuote,
+    bid: &TickerQuote,
+) -> Result<(), ValidationError> {
+    if ask.price.value() < bid.price.value() {
+        Err(validation_error::new("ask price cannot be below bid price"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_low_is_not_above_high(
+    low: &TickerWindow<CurrencyAmount>,
+    high: &TickerWindow<CurrencyAmount>,
+) -> Result<(), ValidationError> {
+    let is_today_valid = low.today.value() <= high.today.value();
+    let is_last_24_hours_valid = low.last_24_hours.value() <= high.last_24_hours.value();
+    if is_today_valid && is_last_24_hours_valid {
+        Ok(())
+    } else {
+        Err(validation_error::new(
+            "low price cannot be above high price",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_amount::{self, CurrencyAmount};
+
+    use super::{new, new_quote, new_trade, new_window, TickerQuote, TickerWindow};
+
+    #[test]
+    fn should_new_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let ask = quote(30300.1);
+        let bid = quote(30300.0);
+        let low = window(29868.3, 29868.3);
+        let high = window(31631.0, 31631.0);
+
+        //Act
+        let result = new(
+            String::from("XXBTZUSD"),
+            ask,
+            bid,
+            new_trade(amount(30303.2), amount(0.0006)).unwrap(),
+            window(4083.6, 4412.7),
+            window(30706.7, 30689.1),
+            new_window(34619, 38907).unwrap(),
+            low,
+            high,
+            amount(30502.8),
+        );
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_ask_is_below_bid() {
+        //Arrange
+        let ask = quote(30299.9);
+        let bid = quote(30300.0);
+        let low = window(29868.3, 29868.3);
+        let high = window(31631.0, 31631.0);
+
+        //Act
+        let result = new(
+            String::from("XXBTZUSD"),
+            ask,
+            bid,
+            new_trade(amount(30303.2), amount(0.0006)).unwrap(),
+            window(4083.6, 4412.7),
+            window(30706.7, 30689.1),
+            new_window(34619, 38907).unwrap(),
+            low,
+            high,
+            amount(30502.8),
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_low_is_above_high() {
+        //Arrange
+        let ask = quote(30300.1);
+        let bid = quote(30300.0);
+        let low = window(29868.3, 31700.0);
+        let high = window(31631.0, 31631.0);
+
+        //Act
+        let result = new(
+            String::from("XXBTZUSD"),
+            ask,
+            bid,
+            new_trade(amount(30303.2), amount(0.0006)).unwrap(),
+            window(4083.6, 4412.7),
+            window(30706.7, 30689.1),
+            new_window(34619, 38907).unwrap(),
+            low,
+            high,
+            amount(30502.8),
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn amount(value: f32) -> CurrencyAmount {
+        currency_amount::new(value).unwrap()
+    }
+
+    fn quote(price: f32) -> TickerQuote {
+        new_quote(amount(price), amount(1.0), amount(1.0)).unwrap()
+    }
+
+    fn window(today: f32, last_24_hours: f32) -> TickerWindow<CurrencyAmount> {
+        new_window(amount(today), amount(last_24_hours)).unwrap()
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    open_order_description::{OrderType, Position},
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct Trade {
+    identifier: String,
+    order_txid: String,
+    position_txid: Option<String>,
+    pair: String,
+    time: f64,
+    position: Position,
+    order_type: OrderType,
+    price: f64,
+    cost: f64,
+    fee: f64,
+    volume: f64,
+    margin: f64,
+    maker: Option<bool>,
+    misc: String,
+}
+
+impl Trade {
+    pub fn identifier(&self) -> &String {
+        &self.identifier
+    }
+
+    pub fn order_txid(&self) -> &String {
+        &self.order_txid
+    }
+
+    pub fn position_txid(&self) -> Option<&String> {
+        self.position_txid.as_ref()
+    }
+
+    pub fn pair(&self) -> &String {
+        &self.pair
+    }
+
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    pub fn order_type(&self) -> OrderType {
+        self.order_type
+    }
+
+    pub fn price(&self) -> f64 {
+        self.price
+    }
+
+    pub fn cost(&self) -> f64 {
+        self.cost
+    }
+
+    pub fn fee(&self) -> f64 {
+        self.fee
+    }
+
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+    pub fn margin(&self) -> f64 {
+        self.margin
+    }
+
+    //None when the source doesn't say, trades read from an export carry no maker flag
+    pub fn is_maker(&self) -> Option<bool> {
+        self.maker
+    }
+
+    pub fn misc(&self) -> &String {
+        &self.misc
+    }
+}
+
+pub fn new(
+    identifier: String,
+    order_txid: String,
+    position_txid: Option<String>,
+    pair: String,
+    time: f64,
+    position: Position,
+    order_type: OrderType,
+    price: f64,
+    cost: f64,
+    fee: f64,
+    volume: f64,
+    margin: f64,
+    maker: Option<bool>,
+    misc: String,
+) -> Result<Trade, ValidationError> {
+    let _ = validate_order_txid(&order_txid)?;
+    let _ = validate_amounts_are_not_negative(&[price, cost, fee, volume, margin])?;
+    let _ = validate_fee_does_not_exceed_cost(fee, cost)?;
+    Ok(Trade {
+        identifier: identifier,
+        order_txid: order_txid,
+        position_txid: position_txid,
+        pair: pair,
+        time: time,
+        position: position,
+        order_type: order_type,
+        price: price,
+        cost: cost,
+        fee: fee,
+        volume: volume,
+        margin: margin,
+        maker: maker,
+        misc: misc,
+    })
+}
+
+fn validate_order_txid(order_txid: &String) -> Result<(), ValidationError> {
+    if order_txid.is_empty() {
+        Err(validation_error::new("a trade needs the txid of its order"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_amounts_are_not_negative(amounts: &[f64]) -> Result<(), ValidationError> {
+    if amounts.iter().any(|amount| *amount < 0.0) {
+        Err(validation_error::new("trade amounts cannot be negative"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_fee_does_not_exceed_cost(fee: f64, cost: f64) -> Result<(), ValidationError> {
+    if fee > cost {
+        Err(validation_error::new("trade fee cannot exceed its cost"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        open_order_description::{OrderType, Position},
+        validation_error::ValidationError,
+    };
+
+    use super::{new, Trade};
+
+    #[test]
+    fn should_new_return_ok_if_all_validation_criteria_are_satisfied() {
+        //Arrange
+        let order_txid = String::from("OQCLML-BW3P3-BUCMWZ");
+        let fee = 0.8;
+
+        //Act
+        let result = trade(order_txid, fee);
+
+        //Assert
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_new_return_error_if_order_txid_is_empty() {
+        //Arrange
+        let order_txid = String::from("");
+        let fee = 0.8;
+
+        //Act
+        let result = trade(order_txid, fee);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_fee_exceeds_cost() {
+        //Arrange
+        let order_txid = String::from("OQCLML-BW3P3-BUCMWZ");
+        let fee = 400.0;
+
+        //Act
+        let result = trade(order_txid, fee);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_return_error_if_fee_is_negative() {
+        //Arrange
+        let order_txid = String::from("OQCLML-BW3P3-BUCMWZ");
+        let fee = -0.8;
+
+        //Act
+        let result = trade(order_txid, fee);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    fn trade(order_txid: String, fee: f64) -> Result<Trade, ValidationError> {
+        new(
+            String::from("TCWJEG-FL4SZ-3FKGH6"),
+            order_txid,
+            None,
+            String::from("XXBTZUSD"),
+            1688667796.8802,
+            Position::Buy,
+            OrderType::Limit,
+            30010.0,
+            300.1,
+            fee,
+            0.01,
+            0.0,
+            Some(true),
+            String::from(""),
+        )
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    asset_pair::AssetPairMargins,
+    currency_amount::CurrencyAmount,
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct TradeBalance {
+    equivalent_balance: CurrencyAmount,
+    trade_balance: CurrencyAmount,
+    margin_used: CurrencyAmount,
+    unrealised_pnl: f32,
+    cost_basis: CurrencyAmount,
+    floating_valuation: CurrencyAmount,
+    equity: CurrencyAmount,
+    free_margin: f32,
+    margin_level: Option<f32>,
+    unexecuted_value: Option<CurrencyAmount>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarginStatus {
+    Healthy,
+    ApproachingMarginCall,
+    MarginCall,
+    MarginStop,
+}
+
+impl TradeBalance {
+    pub fn equivalent_balance(&self) -> &CurrencyAmount {
+        &self.equivalent_balance
+    }
+
+    pub fn trade_balance(&self) -> &CurrencyAmount {
+        &self.trade_balance
+    }
+
+    pub fn margin_used(&self) -> &CurrencyAmount {
+        &self.margin_used
+    }
+
+    pub fn unrealised_pnl(&self) -> f32 {
+        self.unrealised_pnl
+    }
+
+    pub fn cost_basis(&self) -> &CurrencyAmount {
+        &self.cost_basis
+    }
+
+    pub fn floating_valuation(&self) -> &CurrencyAmount {
+        &self.floating_valuation
+    }
+
+    pub fn equity(&self) -> &CurrencyAmount {
+        &self.equity
+    }
+
+    pub fn free_margin(&self) -> f32 {
+        self.free_margin
+    }
+
+    pub fn margin_level(&self) -> Option<f32> {
+        self.margin_level
+    }
+
+    pub fn unexecuted_value(&self) -> Option<&CurrencyAmount> {
+        self.unexecuted_value.as_ref()
+    }
+
+    //The margin level is only reported while there are open positions, so an
+    //account without one can't be anywhere near a margin call. The warning buffer
+    //is expressed in percentage points above the margin call level
+    pub fn margin_status(&self, margins: &AssetPairMargins, warning_buffer: u32) -> MarginStatus {
+        let margin_call = margins.margin_call().value() as f32;
+        let margin_stop = margins.margin_stop().value() as f32;
+        match self.margin_level {
+            None => MarginStatus::Healthy,
+            Some(level) if level <= margin_stop => MarginStatus::MarginStop,
+            Some(level) if level <= margin_call => MarginStatus::MarginCall,
+            Some(level) if level <= margin_call + warning_buffer as f32 => {
+                MarginStatus::ApproachingMarginCall
+            }
+            Some(_) => MarginStatus::Healthy,
+        }
+    }
+}
+
+pub fn new(
+    equivalent_balance: CurrencyAmount,
+    trade_balance: CurrencyAmount,
+    margin_used: CurrencyAmount,
+    unrealised_pnl: f32,
+    cost_basis: CurrencyAmount,
+    floating_valuation: CurrencyAmount,
+    equity: CurrencyAmount,
+    free_margin: f32,
+    margin_level: Option<f32>,
+    unexecuted_value: Option<CurrencyAmount>,
+) -> Result<TradeBalance, ValidationError> {
+    let _ = validate_margin_level(&margin_used, margin_level)?;
+    Ok(TradeBalance {
+        equivalent_balance: equivalent_balance,
+        trade_balance: trade_balance,
+        margin_used: margin_used,
+        unrealised_pnl: unrealised_pnl,
+        cost_basis: cost_basis,
+        floating_valuation: floating_valuation,
+        equity: equity,
+        free_margin: free_margin,
+        margin_level: margin_level,
+        unexecuted_value: unexecuted_value,
+    })
+}
+
+fn validate_margin_level(
+    margin_used: &CurrencyAmount,
+    margin_level: Option<f32>,
+) -> Result<(), ValidationError> {
+    match margin_level {
+        Some(level) if level < 0.0 => Err(validation_error::new("margin level cannot be negative")),
+        Some(_) if margin_used.value() == 0.0 => Err(validation_error::new(
+            "margin level cannot be reported when no margin is used",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        asset_pair::{self, AssetPairMargins},
+        currency_amount, margin,
+        validation_error::ValidationError,
+    };
+
+    use super::{new, MarginStatus, TradeBalance};
+
+    #[test]
+    fn should_new_return_error_if_margin_level_is_reported_without_margin_used() {
+        //Arrange
+        let margin_used = 0.0;
+        let margin_level = Some(250.0);
+
+        //Act
+        let result = trade_balance(margin_used, margin_level);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_margin_status_be_healthy_if_there_are_no_open_positions() {
+        //Arrange
+        let trade_balance = trade_balance(0.0, None).unwrap();
+        let margins = margins();
+
+        //Act
+        let status = trade_balance.margin_status(&margins, 20);
+
+        //Assert
+        assert_eq!(status, MarginStatus::Healthy);
+    }
+
+    #[test]
+    fn should_margin_status_flag_accounts_within_the_warning_buffer() {
+        //Arrange
+        let trade_balance = trade_balance(1000.0, Some(95.0)).unwrap();
+        let margins = margins();
+
+        //Act
+        let status = trade_balance.margin_status(&margins, 20);
+
+        //Assert
+        assert_eq!(status, MarginStatus::ApproachingMarginCall);
+    }
+
+    #[test]
+    fn should_margin_status_return_margin_call_below_the_margin_call_level() {
+        //Arrange
+        let trade_balance = trade_balance(1000.0, Some(75.0)).unwrap();
+        let margins = margins();
+
+        //Act
+        let status = trade_balance.margin_status(&margins, 20);
+
+        //Assert
+        assert_eq!(status, MarginStatus::MarginCall);
+    }
+
+    #[test]
+    fn should_margin_status_return_margin_stop_below_the_margin_stop_level() {
+        //Arrange
+        let trade_balance = trade_balance(1000.0, Some(35.0)).unwrap();
+        let margins = margins();
+
+        //Act
+        let status = trade_balance.margin_status(&margins, 20);
+
+        //Assert
+        assert_eq!(status, MarginStatus::MarginStop);
+    }
+
+    fn trade_balance(
+        margin_used: f32,
+        margin_level: Option<f32>,
+    ) -> Result<TradeBalance, ValidationError> {
+        new(
+            currency_amount::new(10000.0).unwrap(),
+            currency_amount::new(8000.0).unwrap(),
+            currency_amount::new(margin_used).unwrap(),
+            -120.5,
+            currency_amount::new(5000.0).unwrap(),
+            currency_amount::new(4879.5).unwrap(),
+            currency_amount::new(7879.5).unwrap(),
+            6879.5,
+            margin_level,
+            None,
+        )
+    }
+
+    fn margins() -> AssetPairMargins {
+        asset_pair::new_margins(margin::new(80).unwrap(), margin::new(40).unwrap()).unwrap()
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use crate::{
+    currency_amount::CurrencyAmount,
+    fee_set::{FeeSet, FeeTier},
+    validation_error::{self, ValidationError},
+};
+
+#[derive(Debug)]
+pub struct TradeVolume {
+    currency: String,
+    volume: CurrencyAmount,
+    fees: HashMap<String, PairFee>,
+    fees_maker: HashMap<String, PairFee>,
+}
+
+#[derive(Debug)]
+pub struct PairFee {
+    fee: f32,
+    min_fee: f32,
+    max_fee: f32,
+    next_fee: Option<f32>,
+    tier_volume: Option<f32>,
+    next_volume: Option<f32>,
+}
+
+#[derive(Debug)]
+pub struct FeeTierStatus {
+    current_tier: FeeTier,
+    next_tier: Option<FeeTier>,
+    volume_to_next_tier: Option<f32>,
+    reported_fee: f32,
+}
+
+impl TradeVolume {
+    pub fn currency(&self) -> &String {
+        &self.currency
+    }
+
+    pub fn volume(&self) -> &CurrencyAmount {
+        &self.volume
+    }
+
+    pub fn fees(&self) -> &HashMap<String, PairFee> {
+        &self.fees
+    }
+
+    pub fn fees_maker(&self) -> &HashMap<String, PairFee> {
+        &self.fees_maker
+    }
+
+    pub fn taker_tier_status(
+        &self,
+        pair: &str,
+        fee_set: &FeeSet,
+    ) -> Result<FeeTierStatus, ValidationError> {
+        tier_status(self.volume.value(), self.fees.get(pair), fee_set)
+    }
+
+    pub fn maker_tier_status(
+        &self,
+        pair: &str,
+        fee_set: &FeeSet,
+    ) -> Result<FeeTierStatus, ValidationError> {
+        tier_status(self.volume.value(), self.fees_maker.get(pair), fee_set)
+    }
+}
+
+impl PairFee {
+    pub fn fee(&self) -> f32 {
+        self.fee
+    }
+
+    pub fn min_fee(&self) -> f32 {
+        self.min_fee
+    }
+
+    pub fn max_fee(&self) -> f32 {
+        self.max_fee
+    }
+
+    pub fn next_fee(&self) -> Option<f32> {
+        self.next_fee
+    }
+
+    pub fn tier_volume(&self) -> Option<f32> {
+        self.tier_volume
+    }
+
+    pub fn next_volume(&self) -> Option<f32> {
+        self.next_volume
+    }
+}
+
+impl FeeTierStatus {
+    pub fn current_tier(&self) -> &FeeTier {
+        &self.current_tier
+    }
+
+    pub fn next_tier(&self) -> Option<&FeeTier> {
+        self.next_tier.as_ref()
+    }
+
+    pub fn volume_to_next_tier(&self) -> Option<f32> {
+        self.volume_to_next_tier
+    }
+
+    pub fn reported_fee(&self) -> f32 {
+        self.reported_fee
+    }
+
+    //Kraken reports fees with four decimals, so anything closer than that is
+    //considered the same fee
+    pub fn matches_reported_fee(&self) -> bool {
+        (self.current_tier.fee() - self.reported_fee).abs() < 0.0001
+    }
+}
+
+pub fn new(
+    currency: String,
+    volume: CurrencyAmount,
+    fees: HashMap<String, PairFee>,
+    fees_maker: HashMap<String, PairFee>,
+) -> Result<TradeVolume, ValidationError> {
+    Ok(TradeVolume {
+        currency: currency,
+        volume: volume,
+        fees: fees,
+        fees_maker: fees_maker,
+    })
+}
+
+pub fn new_pair_fee(
+    fee: f32,
+    min_fee: f32,
+    max_fee: f32,
+    next_fee: Option<f32>,
+    tier_volume: Option<f32>,
+    next_volume: Option<f32>,
+) -> Result<PairFee, ValidationError> {
+    let _ = validate_fee_is_within_bounds(fee, min_fee, max_fee)?;
+    Ok(PairFee {
+        fee: fee,
+        min_fee: min_fee,
+        max_fee: max_fee,
+        next_fee: next_fee,
+        tier_volume: tier_volume,
+        next_volume: next_volume,
+    })
+}
+
+fn tier_status(
+    volume: f32,
+    pair_fee: Option<&PairFee>,
+    fee_set: &FeeSet,
+) -> Result<FeeTierStatus, ValidationError> {
+    let pair_fee =
+        pair_fee.ok_or_else(|| validation_error::new("no fee was reported for the pair"))?;
+    let current_tier = fee_set
+        .tier_for(volume)
+        .ok_or_else(|| validation_error::new("volume is below the lowest fee tier"))?;
+    let next_tier = fee_set.next_tier(volume);
+    Ok(FeeTierStatus {
+        current_tier: current_tier,
+        next_tier: next_tier,
+        volume_to_next_tier: next_tier.map(|tier| tier.volume() as f32 - volume),
+        reported_fee: pair_fee.fee,
+    })
+}
+
+fn validate_fee_is_within_bounds(
+    fee: f32,
+    min_fee: f32,
+    max_fee: f32,
+) -> Result<(), ValidationError> {
+    if fee < min_fee || fee > max_fee {
+        Err(validation_error::new(
+            "fee needs to be between the minimum and maximum fee",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+
+    use crate::{currency_amount, fee_set};
+
+    use super::{new, new_pair_fee};
+
+    #[test]
+    fn should_new_pair_fee_return_error_if_fee_is_above_maximum_fee() {
+        //Arrange
+        let fee = 0.3;
+
+        //Act
+        let result = new_pair_fee(fee, 0.1, 0.26, None, None, None);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_taker_tier_status_return_volume_needed_for_next_tier() {
+        //Arrange
+        let fee_set = fee_set::new(
+            [(0, 0.26), (50000, 0.24), (100000, 0.22)]
+                .into_iter()
+                .collect::<BTreeMap<u32, f32>>(),
+        )
+        .unwrap();
+        let fees = [(
+            String::from("XXBTZUSD"),
+            new_pair_fee(0.24, 0.1, 0.26, Some(0.22), Some(50000.0), Some(100000.0)).unwrap(),
+        )]
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+        let trade_volume = new(
+            String::from("ZUSD"),
+            currency_amount::new(60000.0).unwrap(),
+            fees,
+            HashMap::new(),
+        )
+        .unwrap();
+
+        //Act
+        let status = trade_volume
+            .taker_tier_status("XXBTZUSD", &fee_set)
+            .unwrap();
+
+        //Assert
+        assert_eq!(status.volume_to_next_tier(), Some(40000.0));
+        assert!(status.matches_reported_fee());
+    }
+
+    #[test]
+    fn should_maker_tier_status_return_error_if_pair_fee_was_not_reported() {
+        //Arrange
+        let fee_set = fee_set::new(BTreeMap::new()).unwrap();
+        let trade_volume = new(
+            String::from("ZUSD"),
+            currency_amount::new(60000.0).unwrap(),
+            HashMap::new(),
+            HashMap::new(),
+        )
+        .unwrap();
+
+        //Act
+        let result = trade_volume.maker_tier_status("XXBTZUSD", &fee_set);
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,238 @@
+use crate::validation_error::{self, ValidationError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Wallet {
+    Spot,
+    Futures,
+}
+
+//Moves funds from the spot wallet to the futures wallet of the same account
+#[derive(Debug, Clone)]
+pub struct WalletTransfer {
+    asset: String,
+    from: Wallet,
+    to: Wallet,
+    amount: f64,
+}
+
+//Moves funds between the master account and its subaccounts
+#[derive(Debug, Clone)]
+pub struct AccountTransfer {
+    asset: String,
+    amount: f64,
+    from: String,
+    to: String,
+}
+
+#[derive(Debug)]
+pub struct AccountTransferConfirmation {
+    transfer_id: String,
+    status: String,
+}
+
+impl Wallet {
+    pub fn value(&self) -> &'static str {
+        match self {
+            Wallet::Spot => "Spot Wallet",
+            Wallet::Futures => "Futures Wallet",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Result<Wallet, ValidationError> {
+        match value {
+            "Spot Wallet" => Ok(Wallet::Spot),
+            "Futures Wallet" => Ok(Wallet::Futures),
+            _ => Err(validation_error::new("unknown wallet")),
+        }
+    }
+}
+
+impl WalletTransfer {
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn from(&self) -> Wallet {
+        self.from
+    }
+
+    pub fn to(&self) -> Wallet {
+        self.to
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+}
+
+impl AccountTransfer {
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    //The IIBAN of the account the funds leave
+    pub fn from(&self) -> &String {
+        &self.from
+    }
+
+    //The IIBAN of the account the funds arrive in
+    pub fn to(&self) -> &String {
+        &self.to
+    }
+}
+
+impl AccountTransferConfirmation {
+    pub fn transfer_id(&self) -> &String {
+        &self.transfer_id
+    }
+
+    pub fn status(&self) -> &String {
+        &self.status
+    }
+}
+
+pub fn new_wallet_transfer(
+    asset: String,
+    from: Wallet,
+    to: Wallet,
+    amount: f64,
+) -> Result<WalletTransfer, ValidationError> {
+    let _ = validate_asset(&asset)?;
+    let _ = validate_wallets(from, to)?;
+    let _ = validate_amount(amount)?;
+    Ok(WalletTransfer {
+        asset: asset,
+        from: from,
+        to: to,
+        amount: amount,
+    })
+}
+
+pub fn new_account_transfer(
+    asset: String,
+    amount: f64,
+    from: String,
+    to: String,
+) -> Result<AccountTransfer, ValidationError> {
+    let _ = validate_asset(&asset)?;
+    let _ = validate_accounts(&from, &to)?;
+    let _ = validate_amount(amount)?;
+    Ok(AccountTransfer {
+        asset: asset,
+        amount: amount,
+        from: from,
+        to: to,
+    })
+}
+
+pub fn new_account_transfer_confirmation(
+    transfer_id: String,
+    status: String,
+) -> AccountTransferConfirmation {
+    AccountTransferConfirmation {
+        transfer_id: transfer_id,
+        status: status,
+    }
+}
+
+fn validate_asset(asset: &String) -> Result<(), ValidationError> {
+    if asset.is_empty() {
+        Err(validation_error::new("a transfer needs an asset"))
+    } else {
+        Ok(())
+    }
+}
+
+//Kraken only moves funds into the futures wallet, withdrawing them back to spot
+//has to be done from the futures platform
+fn validate_wallets(from: Wallet, to: Wallet) -> Result<(), ValidationError> {
+    if from != Wallet::Spot || to != Wallet::Futures {
+        Err(validation_error::new(
+            "a transfer can only go from the spot wallet to the futures wallet",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_accounts(from: &String, to: &String) -> Result<(), ValidationError> {
+    if from.is_empty() || to.is_empty() {
+        Err(validation_error::new("a transfer needs both accounts"))
+    } else if from == to {
+        Err(validation_error::new(
+            "a transfer needs two different accounts",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_amount(amount: f64) -> Result<(), ValidationError> {
+    if amount <= 0.0 {
+        Err(validation_error::new("a transfer needs a positive amount"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{new_account_transfer, new_wallet_transfer, Wallet};
+
+    #[test]
+    fn should_new_wallet_transfer_return_error_if_both_wallets_are_the_same() {
+        //Arrange
+        let wallet = Wallet::Spot;
+
+        //Act
+        let result = new_wallet_transfer(String::from("XBT"), wallet, wallet, 0.5);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_wallet_transfer_return_error_if_funds_leave_the_futures_wallet() {
+        //Arrange
+        let from = Wallet::Futures;
+
+        //Act
+        let result = new_wallet_transfer(String::from("XBT"), from, Wallet::Spot, 0.5);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_account_transfer_return_error_if_both_accounts_are_the_same() {
+        //Arrange
+        let account = String::from("AA81 N84G 3CQK GKNC YPCV");
+
+        //Act
+        let result = new_account_transfer(String::from("XBT"), 0.5, account.clone(), account);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_account_transfer_return_error_if_the_amount_is_zero() {
+        //Arrange
+        let amount = 0.0;
+
+        //Act
+        let result = new_account_transfer(
+            String::from("XBT"),
+            amount,
+            String::from("AA81 N84G 3CQK GKNC YPCV"),
+            String::from("AA25 N84G WVGT WQPW 2AO5"),
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,26 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    message: String,
+}
+
+pub fn new(message: &str) -> ValidationError {
+    ValidationError {
+        message: String::from(message),
+    }
+}
+
+impl ValidationError {
+    pub fn message(&self) -> &String {
+        &self.message
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
@@ -0,0 +1,524 @@
+use crate::{
+    funding_status::{FundingStatus, FundingStatusProperty},
+    new_order::has_at_most_decimals,
+    validation_error::{self, ValidationError},
+};
+
+//What kraken quotes for withdrawing an amount to a given withdrawal key
+#[derive(Debug)]
+pub struct WithdrawalInfo {
+    asset: String,
+    key: String,
+    method: String,
+    limit: f64,
+    amount: f64,
+    fee: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct WithdrawalRequest {
+    asset: String,
+    key: String,
+    amount: f64,
+    address: Option<String>,
+    max_fee: Option<f64>,
+    decimals: usize,
+}
+
+#[derive(Debug)]
+pub struct Withdrawal {
+    method: String,
+    network: Option<String>,
+    asset_class: String,
+    asset: String,
+    refid: String,
+    txid: Option<String>,
+    info: String,
+    amount: f64,
+    fee: f64,
+    time: u64,
+    status: FundingStatus,
+    status_property: Option<FundingStatusProperty>,
+}
+
+impl WithdrawalInfo {
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn key(&self) -> &String {
+        &self.key
+    }
+
+    pub fn method(&self) -> &String {
+        &self.method
+    }
+
+    pub fn limit(&self) -> f64 {
+        self.limit
+    }
+
+    //The amount that will actually be sent once the fee is taken out
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn fee(&self) -> f64 {
+        self.fee
+    }
+}
+
+impl WithdrawalRequest {
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn key(&self) -> &String {
+        &self.key
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    //Must match the address stored under the withdrawal key
+    pub fn address(&self) -> Option<&String> {
+        self.address.as_ref()
+    }
+
+    pub fn max_fee(&self) -> Option<f64> {
+        self.max_fee
+    }
+
+    pub fn format_amount(&self) -> String {
+        format!("{:.*}", self.decimals, self.amount)
+    }
+
+    pub fn format_max_fee(&self) -> Option<String> {
+        self.max_fee
+            .map(|max_fee| format!("{:.*}", self.decimals, max_fee))
+    }
+}
+
+impl Withdrawal {
+    pub fn method(&self) -> &String {
+        &self.method
+    }
+
+    pub fn network(&self) -> Option<&String> {
+        self.network.as_ref()
+    }
+
+    pub fn asset_class(&self) -> &String {
+        &self.asset_class
+    }
+
+    pub fn asset(&self) -> &String {
+        &self.asset
+    }
+
+    pub fn refid(&self) -> &String {
+        &self.refid
+    }
+
+    //None until the withdrawal has been broadcast
+    pub fn txid(&self) -> Option<&String> {
+        self.txid.as_ref()
+    }
+
+    pub fn info(&self) -> &String {
+        &self.info
+    }
+
+    pub fn amount(&self) -> f64 {
+        self.amount
+    }
+
+    pub fn fee(&self) -> f64 {
+        self.fee
+    }
+
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    pub fn status(&self) -> FundingStatus {
+        self.status
+    }
+
+    pub fn status_property(&self) -> Option<FundingStatusProperty> {
+        self.status_property
+    }
+
+    //Kraken only cancels withdrawals it hasn't started processing yet
+    pub fn is_cancellable(&self) -> bool {
+        let cancelling = matches!(
+            self.status_property,
+            Some(FundingStatusProperty::CancelPending) | Some(FundingStatusProperty::Canceled)
+        );
+        matches!(self.status, FundingStatus::Initial | FundingStatus::Pending) && !cancelling
+    }
+}
+
+//The asset and key are the ones the quote was requested for, kraken doesn't echo them
+pub fn new_info(
+    asset: String,
+    key: String,
+    method: String,
+    limit: f64,
+    amount: f64,
+    fee: f64,
+) -> Result<WithdrawalInfo, ValidationError> {
+    let _ = validate_quoted_amounts(limit, amount, fee)?;
+    Ok(WithdrawalInfo {
+        asset: asset,
+        key: key,
+        method: method,
+        limit: limit,
+        amount: amount,
+        fee: fee,
+    })
+}
+
+//Amounts are sent with the decimals of the asset, so kraken gets exactly the
+//amount that was validated
+pub fn new_request(
+    asset: String,
+    key: String,
+    amount: f64,
+    address: Option<String>,
+    max_fee: Option<f64>,
+    decimals: usize,
+    info: &WithdrawalInfo,
+) -> Result<WithdrawalRequest, ValidationError> {
+    let _ = validate_asset(&asset)?;
+    let _ = validate_key(&key)?;
+    let _ = validate_info(&asset, &key, info)?;
+    let _ = validate_amount(amount, decimals, info)?;
+    let _ = validate_max_fee(max_fee, decimals)?;
+    Ok(WithdrawalRequest {
+        asset: asset,
+        key: key,
+        amount: amount,
+        address: address,
+        max_fee: max_fee,
+        decimals: decimals,
+    })
+}
+
+pub fn new(
+    method: String,
+    network: Option<String>,
+    asset_class: String,
+    asset: String,
+    refid: String,
+    txid: Option<String>,
+    info: String,
+    amount: f64,
+    fee: f64,
+    time: u64,
+    status: FundingStatus,
+    status_property: Option<FundingStatusProperty>,
+) -> Result<Withdrawal, ValidationError> {
+    let _ = validate_refid(&refid)?;
+    let _ = validate_amount_and_fee(amount, fee)?;
+    Ok(Withdrawal {
+        method: method,
+        network: network,
+        asset_class: asset_class,
+        asset: asset,
+        refid: refid,
+        txid: txid,
+        info: info,
+        amount: amount,
+        fee: fee,
+        time: time,
+        status: status,
+        status_property: status_property,
+    })
+}
+
+fn validate_asset(asset: &String) -> Result<(), ValidationError> {
+    if asset.is_empty() {
+        Err(validation_error::new("a withdrawal needs an asset"))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_key(key: &String) -> Result<(), ValidationError> {
+    if key.is_empty() {
+        Err(validation_error::new(
+            "a withdrawal needs a withdrawal key name",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+//The limit only holds for the asset and key it was quoted for
+fn validate_info(
+    asset: &String,
+    key: &String,
+    info: &WithdrawalInfo,
+) -> Result<(), ValidationError> {
+    if *asset != info.asset || *key != info.key {
+        Err(validation_error::new(&format!(
+            "the withdrawal info was quoted for {} to {}",
+            info.asset, info.key
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_amount(
+    amount: f64,
+    decimals: usize,
+    info: &WithdrawalInfo,
+) -> Result<(), ValidationError> {
+    if amount <= 0.0 {
+        Err(validation_error::new(
+            "a withdrawal needs a positive amount",
+        ))
+    } else if !has_at_most_decimals(amount, decimals) {
+        Err(validation_error::new(
+            "a withdrawal amount has more decimals than the asset allows",
+        ))
+    } else if amount > info.limit {
+        Err(validation_error::new(&format!(
+            "a withdrawal cannot exceed the limit of {}",
+            info.limit
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_max_fee(max_fee: Option<f64>, decimals: usize) -> Result<(), ValidationError> {
+    match max_fee {
+        Some(max_fee) if max_fee < 0.0 => Err(validation_error::new(
+            "a withdrawal max fee cannot be negative",
+        )),
+        Some(max_fee) if !has_at_most_decimals(max_fee, decimals) => Err(validation_error::new(
+            "a withdrawal max fee has more decimals than the asset allows",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn validate_quoted_amounts(limit: f64, amount: f64, fee: f64) -> Result<(), ValidationError> {
+    if limit < 0.0 {
+        Err(validation_error::new(
+            "a withdrawal limit cannot be negative",
+        ))
+    } else {
+        validate_amount_and_fee(amount, fee)
+    }
+}
+
+fn validate_amount_and_fee(amount: f64, fee: f64) -> Result<(), ValidationError> {
+    if amount < 0.0 || fee < 0.0 {
+        Err(validation_error::new(
+            "a withdrawal amount and fee cannot be negative",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn validate_refid(refid: &String) -> Result<(), ValidationError> {
+    if refid.is_empty() {
+        Err(validation_error::new("a withdrawal needs a reference id"))
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::funding_status::{FundingStatus, FundingStatusProperty};
+
+    use super::{new, new_info, new_request, WithdrawalInfo};
+
+    #[test]
+    fn should_new_request_return_error_if_the_amount_is_negative() {
+        //Arrange
+        let info = info();
+
+        //Act
+        let result = new_request(
+            String::from("XBT"),
+            String::from("cold storage"),
+            -0.5,
+            None,
+            None,
+            8,
+            &info,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_request_return_error_if_the_amount_exceeds_the_limit() {
+        //Arrange
+        let info = info();
+
+        //Act
+        let result = new_request(
+            String::from("XBT"),
+            String::from("cold storage"),
+            2.5,
+            None,
+            None,
+            8,
+            &info,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_request_return_error_if_the_key_is_empty() {
+        //Arrange
+        let info = info();
+
+        //Act
+        let result = new_request(
+            String::from("XBT"),
+            String::new(),
+            0.5,
+            None,
+            None,
+            8,
+            &info,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_request_return_error_if_the_info_was_quoted_for_another_key() {
+        //Arrange
+        let info = info();
+
+        //Act
+        let result = new_request(
+            String::from("XBT"),
+            String::from("hot wallet"),
+            0.5,
+            None,
+            None,
+            8,
+            &info,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_request_return_error_if_the_info_was_quoted_for_another_asset() {
+        //Arrange
+        let info = info();
+
+        //Act
+        let result = new_request(
+            String::from("ETH"),
+            String::from("cold storage"),
+            0.5,
+            None,
+            None,
+            8,
+            &info,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_new_request_return_error_if_the_amount_has_more_decimals_than_the_asset() {
+        //Arrange
+        let info = info();
+
+        //Act
+        let result = new_request(
+            String::from("XBT"),
+            String::from("cold storage"),
+            0.123456789,
+            None,
+            None,
+            8,
+            &info,
+        );
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_format_amount_keep_every_decimal_of_the_asset() {
+        //Arrange
+        let info = info();
+        let request = new_request(
+            String::from("XBT"),
+            String::from("cold storage"),
+            0.12345678,
+            None,
+            Some(0.0002),
+            8,
+            &info,
+        )
+        .unwrap();
+
+        //Act
+        let amount = request.format_amount();
+
+        //Assert
+        assert_eq!(amount, String::from("0.12345678"));
+        assert_eq!(request.format_max_fee(), Some(String::from("0.00020000")));
+    }
+
+    #[test]
+    fn should_is_cancellable_be_false_once_a_cancellation_is_pending() {
+        //Arrange
+        let withdrawal = new(
+            String::from("Bitcoin"),
+            None,
+            String::from("currency"),
+            String::from("XXBT"),
+            String::from("AGBSO6T-UFMTTQ-I7KGS6"),
+            None,
+            String::from("bc1qxdsh4sdd29h6ldehz0se5c61asq8cgwyjf2y3z"),
+            0.5,
+            0.0001,
+            1688992722,
+            FundingStatus::Pending,
+            Some(FundingStatusProperty::CancelPending),
+        )
+        .unwrap();
+
+        //Act
+        let cancellable = withdrawal.is_cancellable();
+
+        //Assert
+        assert!(!cancellable);
+    }
+
+    fn info() -> WithdrawalInfo {
+        new_info(
+            String::from("XBT"),
+            String::from("cold storage"),
+            String::from("Bitcoin"),
+            2.0,
+            0.4999,
+            0.0001,
+        )
+        .unwrap()
+    }
+}
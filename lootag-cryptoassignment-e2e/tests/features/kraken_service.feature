@@ -8,6 +8,30 @@ Feature: Kraken Service
     When I request the XBTUSD trading pair
     Then the api successfully returns a valid XBTUSD trading pair
 
+  Scenario: If I request an unknown trading pair then the api returns an exchange error
+    When I request an unknown trading pair
+    Then the api returns an unknown asset pair error
+
+  Scenario: If I request the margin info of all trading pairs then the api returns them correctly
+    When I request the margin info of all trading pairs
+    Then the api successfully returns a valid set of trading pairs
+
+  Scenario: If I request the XBTUSD ticker then the api returns it correctly
+    When I request the XBTUSD ticker
+    Then the api successfully returns a valid XBTUSD ticker
+
+  Scenario: If I request the hourly XBTUSD candles then the api returns them correctly
+    When I request the hourly XBTUSD candles
+    Then the api successfully returns a valid series of XBTUSD candles
+
+  Scenario: If I request the XBTUSD order book then the api returns it correctly
+    When I request the XBTUSD order book
+    Then the api successfully returns a valid XBTUSD order book
+
+  Scenario: If I stream the XBTUSD trades then the api returns them in order across pages
+    When I stream the XBTUSD trades of the last hour
+    Then the api successfully returns the XBTUSD trades in chronological order
+
   Scenario: If I request my open orders then the api returns them correctly when I provide valid credentials
     Given that I provide a valid set of credentials 
     When I request my open orders
@@ -17,3 +41,88 @@ Feature: Kraken Service
     Given that I provide an invalid set of credentials 
     When I request my open orders
     Then the api does not return my open orders
+
+  Scenario: If I request my extended balance then the api returns it correctly when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request my extended balance
+    Then the api successfully returns my balances
+
+  Scenario: If I request my trade balance then the api returns it correctly when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request my trade balance in US dollars
+    Then the api successfully returns my trade balance
+
+  Scenario: If I stream my closed orders then the api returns them correctly when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I stream my closed orders
+    Then the api successfully returns my finished orders
+
+  Scenario: If I query orders by transaction id then the api returns them correctly when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I query my most recent closed orders by transaction id
+    Then the api successfully returns the orders I queried
+
+  Scenario: If I resolve the trades of an order then the api returns them correctly when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I resolve the trades of my most recent closed order
+    Then the api successfully returns the trades of that order
+
+  Scenario: If I stream my ledger entries then the api returns them correctly when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I stream my trade ledger entries
+    Then the api successfully returns only trade ledger entries
+
+  Scenario: If I request my trade volume then the api returns fees consistent with the fee tiers when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request my XBTUSD trade volume
+    Then the api successfully returns a fee consistent with the XBTUSD fee tiers
+
+  Scenario: If I validate an order then the api describes it without placing it when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I validate a buy limit order of 0.0001 XXBTZUSD at 1000.0
+    Then the api successfully returns the order description without placing the order
+
+  Scenario: If I validate a batch of orders then the api describes each of them when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I validate a ladder of two buy limit orders of 0.0001 XXBTZUSD
+    Then the api successfully returns a description for every order of the ladder
+
+  Scenario: If I cancel all my orders then the service refuses to do it in validate-only mode
+    Given that I provide a valid set of credentials
+    When I cancel all my orders
+    Then the service refuses to cancel them in validate-only mode
+
+  Scenario: If I keep a dead man's switch armed then the service refuses to do it in validate-only mode
+    Given that I provide a valid set of credentials
+    When I keep a dead man's switch of 60 seconds armed every 15 seconds
+    Then the service refuses to arm it in validate-only mode
+
+  Scenario: If I request my open positions then the api returns them with their net value when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request my open positions with their calculations
+    Then the api successfully returns my open positions with their net value
+
+  Scenario: If I request the deposit methods for an asset then the api returns them when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request the deposit methods for XBT
+    Then the api successfully returns at least one deposit method
+
+  Scenario: If I request the status of my withdrawals then the api returns them when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request the status of my recent XBT withdrawals
+    Then the api successfully returns my recent withdrawals
+
+  Scenario: If I transfer funds between my wallets then the service refuses to do it in validate-only mode
+    Given that I provide a valid set of credentials
+    When I transfer 0.001 XBT from my spot wallet to my futures wallet
+    Then the service refuses to make the transfer in validate-only mode
+
+  Scenario: If I request the flexible earn strategies then the api returns them when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request the flexible earn strategies
+    Then the api successfully returns only flexible strategies
+
+  Scenario: If I request the status of my ledger exports then the api returns them when I provide valid credentials
+    Given that I provide a valid set of credentials
+    When I request the status of my ledger exports
+    Then the api successfully returns only ledger exports
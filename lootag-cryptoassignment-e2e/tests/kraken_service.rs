@@ -1,19 +1,47 @@
-use std::{convert::Infallible, time::Duration};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use async_trait::async_trait;
 use cucumber::{given, then, when, World, WorldInit};
+use futures::StreamExt;
 use lootag_cryptoassignment_domain::{
+    asset_pair::{AssetPair, AssetPairInfo},
+    balance::Balances,
+    candle_series::{CandleSeries, Interval},
     credentials::{self, Credentials},
+    export::{Export, ReportType},
+    earn::{EarnStrategy, LockType},
+    deposit::DepositMethod,
+    ledger_entry::{LedgerEntry, LedgerEntryType},
+    new_order,
     open_order::OpenOrder,
+    open_order_description::{OrderType, Position},
+    order_book::OrderBook,
+    order_cancellation::OrderCancellation,
+    order_confirmation::OrderConfirmation,
+    position::Position as MarginPosition,
+    public_trade::PublicTrade,
     server_time::{ServerTime},
-    xbt_usd::XbtUsd,
+    ticker::Ticker,
+    trade::Trade,
+    trade_balance::{MarginStatus, TradeBalance},
+    trade_volume::TradeVolume,
+    transfer::{self, Wallet},
+    withdrawal::Withdrawal,
+};
+use lootag_cryptoassignment_services::{
+    CloseTime, ClosedOrdersFilter, Configuration, DeadManSwitchKeeper, ExchangeError, KrakenError,
+    KrakenService, LedgersFilter,
 };
-use lootag_cryptoassignment_services::{Configuration, KrakenService};
 
 const BASE_URL: &str = "https://api.kraken.com/0";
 const RETRY_POLICY_INITIAL_DURATION: u64 = 2;
 const RETRY_POLICY_MULTIPLIER: f64 = 2.0;
 const RETRY_POLICY_MAXIMUM_DURATION: u64 = 15;
+const VALIDATE_ONLY: bool = true;
 const API_KEY: &str = "you-api-key";
 const PRIVATE_KEY: &str = "your-private-key";
 const OTP_SECRET: &str = "your-otp-secret";
@@ -22,9 +50,30 @@ const OTP_SECRET: &str = "your-otp-secret";
 pub struct WorldImpl {
     kraken_service: KrakenService,
     credentials: Option<Credentials>,
-    server_time: Option<Result<ServerTime, std::io::Error>>,
-    xbt_usd: Option<Result<XbtUsd, std::io::Error>>,
-    open_orders_result: Option<Result<Vec<OpenOrder>, std::io::Error>>,
+    server_time: Option<Result<ServerTime, KrakenError>>,
+    asset_pairs: Option<Result<HashMap<String, AssetPair>, KrakenError>>,
+    ticker: Option<Result<HashMap<String, Ticker>, KrakenError>>,
+    ohlc: Option<Result<CandleSeries, KrakenError>>,
+    order_book: Option<Result<OrderBook, KrakenError>>,
+    trades: Option<Vec<Result<PublicTrade, KrakenError>>>,
+    balances: Option<Result<Balances, KrakenError>>,
+    trade_balance: Option<Result<TradeBalance, KrakenError>>,
+    closed_orders: Option<Vec<Result<OpenOrder, KrakenError>>>,
+    queried_orders: Option<(Vec<String>, Result<Vec<OpenOrder>, KrakenError>)>,
+    order_trades: Option<(Vec<String>, Result<Vec<Trade>, KrakenError>)>,
+    ledger_entries: Option<Vec<Result<LedgerEntry, KrakenError>>>,
+    trade_volume: Option<Result<TradeVolume, KrakenError>>,
+    order_confirmation: Option<Result<OrderConfirmation, KrakenError>>,
+    batch_confirmations: Option<Result<Vec<Result<OrderConfirmation, KrakenError>>, KrakenError>>,
+    cancellation: Option<Result<OrderCancellation, KrakenError>>,
+    dead_man_switch_keeper: Option<Result<DeadManSwitchKeeper, KrakenError>>,
+    open_positions: Option<Result<Vec<MarginPosition>, KrakenError>>,
+    deposit_methods: Option<Result<Vec<DepositMethod>, KrakenError>>,
+    withdrawals: Option<Result<Vec<Withdrawal>, KrakenError>>,
+    wallet_transfer: Option<Result<String, KrakenError>>,
+    earn_strategies: Option<Result<Vec<EarnStrategy>, KrakenError>>,
+    ledger_exports: Option<Result<Vec<Export>, KrakenError>>,
+    open_orders_result: Option<Result<Vec<OpenOrder>, KrakenError>>,
 }
 
 #[async_trait(?Send)]
@@ -36,12 +85,34 @@ impl World for WorldImpl {
             Duration::from_secs(RETRY_POLICY_INITIAL_DURATION),
             RETRY_POLICY_MULTIPLIER,
             Duration::from_secs(RETRY_POLICY_MAXIMUM_DURATION),
+            VALIDATE_ONLY,
         );
         Ok(Self {
             kraken_service: KrakenService::new(configuration),
             credentials: None,
             server_time: None,
-            xbt_usd: None,
+            asset_pairs: None,
+            ticker: None,
+            ohlc: None,
+            order_book: None,
+            trades: None,
+            balances: None,
+            trade_balance: None,
+            closed_orders: None,
+            queried_orders: None,
+            order_trades: None,
+            ledger_entries: None,
+            trade_volume: None,
+            order_confirmation: None,
+            batch_confirmations: None,
+            cancellation: None,
+            dead_man_switch_keeper: None,
+            open_positions: None,
+            deposit_methods: None,
+            withdrawals: None,
+            wallet_transfer: None,
+            earn_strategies: None,
+            ledger_exports: None,
             open_orders_result: None,
         })
     }
@@ -71,8 +142,292 @@ async fn request_server_time(world: &mut WorldImpl) {
 
 #[when("I request the XBTUSD trading pair")]
 async fn request_xbt_usd_pair(world: &mut WorldImpl) {
-    let xbt_usd_result = world.kraken_service.retrieve_xbtusd_pair().await;
-    world.xbt_usd = Some(xbt_usd_result);
+    let asset_pairs_result = world
+        .kraken_service
+        .retrieve_asset_pairs(&[String::from("XXBTZUSD")], AssetPairInfo::Info)
+        .await;
+    world.asset_pairs = Some(asset_pairs_result);
+}
+
+#[when("I request an unknown trading pair")]
+async fn request_unknown_asset_pair(world: &mut WorldImpl) {
+    let asset_pairs_result = world
+        .kraken_service
+        .retrieve_asset_pairs(&[String::from("NOTAPAIR")], AssetPairInfo::Info)
+        .await;
+    world.asset_pairs = Some(asset_pairs_result);
+}
+
+#[when("I request the margin info of all trading pairs")]
+async fn request_all_asset_pairs_margins(world: &mut WorldImpl) {
+    let asset_pairs_result = world
+        .kraken_service
+        .retrieve_all_asset_pairs(AssetPairInfo::Margin)
+        .await;
+    world.asset_pairs = Some(asset_pairs_result);
+}
+
+#[when("I request the XBTUSD ticker")]
+async fn request_xbt_usd_ticker(world: &mut WorldImpl) {
+    let ticker_result = world
+        .kraken_service
+        .retrieve_ticker(&[String::from("XXBTZUSD")])
+        .await;
+    world.ticker = Some(ticker_result);
+}
+
+#[when("I request the hourly XBTUSD candles")]
+async fn request_xbt_usd_ohlc(world: &mut WorldImpl) {
+    let ohlc_result = world
+        .kraken_service
+        .retrieve_ohlc(&String::from("XXBTZUSD"), Interval::OneHour, None)
+        .await;
+    world.ohlc = Some(ohlc_result);
+}
+
+#[when("I request the XBTUSD order book")]
+async fn request_xbt_usd_order_book(world: &mut WorldImpl) {
+    let order_book_result = world
+        .kraken_service
+        .retrieve_order_book(&String::from("XXBTZUSD"), Some(10))
+        .await;
+    world.order_book = Some(order_book_result);
+}
+
+#[when("I stream the XBTUSD trades of the last hour")]
+async fn stream_xbt_usd_trades(world: &mut WorldImpl) {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let since = now.as_secs() - 3600;
+    let pair = String::from("XXBTZUSD");
+    let trades = world
+        .kraken_service
+        .stream_trades(&pair, Some(since))
+        .take(2000)
+        .collect::<Vec<Result<PublicTrade, KrakenError>>>()
+        .await;
+    world.trades = Some(trades);
+}
+
+#[when("I request my extended balance")]
+async fn request_extended_balance(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let balances_result = world
+        .kraken_service
+        .retrieve_extended_balance(credentials)
+        .await;
+    world.balances = Some(balances_result);
+}
+
+#[when("I request my trade balance in US dollars")]
+async fn request_trade_balance(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let trade_balance_result = world
+        .kraken_service
+        .retrieve_trade_balance(credentials, Some(&String::from("ZUSD")))
+        .await;
+    world.trade_balance = Some(trade_balance_result);
+}
+
+#[when("I stream my closed orders")]
+async fn stream_closed_orders(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let filter = ClosedOrdersFilter::new(None, None, CloseTime::Close, None, true);
+    let closed_orders = world
+        .kraken_service
+        .stream_closed_orders(credentials, &filter)
+        .take(120)
+        .collect::<Vec<Result<OpenOrder, KrakenError>>>()
+        .await;
+    world.closed_orders = Some(closed_orders);
+}
+
+#[when("I query my most recent closed orders by transaction id")]
+async fn query_recent_closed_orders(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let filter = ClosedOrdersFilter::default();
+    let txids = world
+        .kraken_service
+        .retrieve_closed_orders(credentials, &filter, None)
+        .await
+        .unwrap()
+        .iter()
+        .map(|order| order.identifier().clone())
+        .collect::<Vec<String>>();
+    let queried_orders_result = world
+        .kraken_service
+        .query_orders(credentials, &txids, false, None)
+        .await;
+    world.queried_orders = Some((txids, queried_orders_result));
+}
+
+#[when("I resolve the trades of my most recent closed order")]
+async fn resolve_recent_order_trades(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let filter = ClosedOrdersFilter::new(None, None, CloseTime::Close, None, true);
+    let orders = world
+        .kraken_service
+        .retrieve_closed_orders(credentials, &filter, None)
+        .await
+        .unwrap();
+    let order = orders
+        .iter()
+        .find(|order| !order.trades().is_empty())
+        .unwrap();
+    let trades_result = world
+        .kraken_service
+        .resolve_order_trades(credentials, order)
+        .await;
+    world.order_trades = Some((order.trades().clone(), trades_result));
+}
+
+#[when("I stream my trade ledger entries")]
+async fn stream_trade_ledger_entries(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let filter = LedgersFilter::new(vec![], Some(LedgerEntryType::Trade), None, None);
+    let ledger_entries = world
+        .kraken_service
+        .stream_ledgers(credentials, &filter)
+        .take(120)
+        .collect::<Vec<Result<LedgerEntry, KrakenError>>>()
+        .await;
+    world.ledger_entries = Some(ledger_entries);
+}
+
+#[when("I request my XBTUSD trade volume")]
+async fn request_trade_volume(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let trade_volume_result = world
+        .kraken_service
+        .retrieve_trade_volume(credentials, &[String::from("XXBTZUSD")])
+        .await;
+    world.trade_volume = Some(trade_volume_result);
+}
+
+#[when("I validate a buy limit order of 0.0001 XXBTZUSD at 1000.0")]
+async fn validate_limit_order(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let pair = String::from("XXBTZUSD");
+    let asset_pairs = world
+        .kraken_service
+        .retrieve_asset_pairs(&[pair.clone()], AssetPairInfo::Info)
+        .await
+        .unwrap();
+    let order = new_order::builder(pair.clone(), Position::Buy, OrderType::Limit, 0.0001)
+        .price(1000.0)
+        .build(&asset_pairs[&pair])
+        .unwrap();
+    world.order_confirmation = Some(
+        world
+            .kraken_service
+            .add_order(credentials, &order, true)
+            .await,
+    );
+}
+
+#[when("I validate a ladder of two buy limit orders of 0.0001 XXBTZUSD")]
+async fn validate_limit_order_ladder(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let pair = String::from("XXBTZUSD");
+    let asset_pairs = world
+        .kraken_service
+        .retrieve_asset_pairs(&[pair.clone()], AssetPairInfo::Info)
+        .await
+        .unwrap();
+    let orders = vec![
+        new_order::builder(pair.clone(), Position::Buy, OrderType::Limit, 0.0001).price(1000.0),
+        new_order::builder(pair.clone(), Position::Buy, OrderType::Limit, 0.0001).price(900.0),
+    ];
+    world.batch_confirmations = Some(
+        world
+            .kraken_service
+            .add_order_batch(credentials, &asset_pairs[&pair], orders, None, true)
+            .await,
+    );
+}
+
+#[when("I cancel all my orders")]
+async fn cancel_all_orders(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    world.cancellation = Some(world.kraken_service.cancel_all(credentials).await);
+}
+
+#[when("I keep a dead man's switch of 60 seconds armed every 15 seconds")]
+async fn keep_dead_man_switch(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    world.dead_man_switch_keeper = Some(world.kraken_service.keep_dead_man_switch(
+        credentials,
+        Duration::from_secs(15),
+        Duration::from_secs(60),
+    ));
+}
+
+#[when("I request my open positions with their calculations")]
+async fn request_open_positions(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    world.open_positions = Some(
+        world
+            .kraken_service
+            .retrieve_open_positions(credentials, &[], true)
+            .await,
+    );
+}
+
+#[when("I request the deposit methods for XBT")]
+async fn request_deposit_methods(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    world.deposit_methods = Some(
+        world
+            .kraken_service
+            .retrieve_deposit_methods(credentials, &String::from("XBT"))
+            .await,
+    );
+}
+
+#[when("I request the status of my recent XBT withdrawals")]
+async fn request_withdrawal_status(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    world.withdrawals = Some(
+        world
+            .kraken_service
+            .retrieve_withdrawal_status(credentials, Some(&String::from("XBT")), None)
+            .await,
+    );
+}
+
+#[when("I transfer 0.001 XBT from my spot wallet to my futures wallet")]
+async fn transfer_to_futures_wallet(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    let transfer =
+        transfer::new_wallet_transfer(String::from("XBT"), Wallet::Spot, Wallet::Futures, 0.001)
+            .unwrap();
+    world.wallet_transfer = Some(
+        world
+            .kraken_service
+            .wallet_transfer(credentials, &transfer)
+            .await,
+    );
+}
+
+#[when("I request the flexible earn strategies")]
+async fn request_flexible_earn_strategies(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    world.earn_strategies = Some(
+        world
+            .kraken_service
+            .retrieve_earn_strategies(credentials, None, &[LockType::Flex])
+            .await,
+    );
+}
+
+#[when("I request the status of my ledger exports")]
+async fn request_ledger_export_status(world: &mut WorldImpl) {
+    let credentials = world.credentials.as_ref().unwrap();
+    world.ledger_exports = Some(
+        world
+            .kraken_service
+            .retrieve_export_status(credentials, ReportType::Ledgers)
+            .await,
+    );
 }
 
 #[when("I request my open orders")]
@@ -89,7 +444,201 @@ async fn assert_server_time_is_ok(world: &mut WorldImpl) {
 
 #[then("the api successfully returns a valid XBTUSD trading pair")]
 async fn assert_xbt_usd_pair_is_ok(world: &mut WorldImpl) {
-    assert!(world.xbt_usd.as_ref().unwrap().is_ok())
+    let asset_pairs = world.asset_pairs.as_ref().unwrap().as_ref().unwrap();
+    assert!(asset_pairs.contains_key("XXBTZUSD"))
+}
+
+#[then("the api returns an unknown asset pair error")]
+async fn assert_asset_pair_is_unknown(world: &mut WorldImpl) {
+    assert!(matches!(
+        world.asset_pairs.as_ref().unwrap(),
+        Err(KrakenError::Exchange(ExchangeError::UnknownAssetPair))
+    ))
+}
+
+#[then("the api successfully returns a valid set of trading pairs")]
+async fn assert_asset_pairs_are_ok(world: &mut WorldImpl) {
+    let asset_pairs = world.asset_pairs.as_ref().unwrap().as_ref().unwrap();
+    assert!(!asset_pairs.is_empty())
+}
+
+#[then("the api successfully returns a valid XBTUSD ticker")]
+async fn assert_xbt_usd_ticker_is_ok(world: &mut WorldImpl) {
+    let ticker = world.ticker.as_ref().unwrap().as_ref().unwrap();
+    assert!(ticker.contains_key("XXBTZUSD"))
+}
+
+#[then("the api successfully returns a valid series of XBTUSD candles")]
+async fn assert_xbt_usd_ohlc_is_ok(world: &mut WorldImpl) {
+    let ohlc = world.ohlc.as_ref().unwrap().as_ref().unwrap();
+    assert!(!ohlc.candles().is_empty())
+}
+
+#[then("the api successfully returns a valid XBTUSD order book")]
+async fn assert_xbt_usd_order_book_is_ok(world: &mut WorldImpl) {
+    let order_book = world.order_book.as_ref().unwrap().as_ref().unwrap();
+    assert!(order_book.spread().unwrap() > 0.0)
+}
+
+#[then("the api successfully returns the XBTUSD trades in chronological order")]
+async fn assert_xbt_usd_trades_are_ok(world: &mut WorldImpl) {
+    let trades = world
+        .trades
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|trade| trade.as_ref().unwrap())
+        .collect::<Vec<&PublicTrade>>();
+    assert!(trades.windows(2).all(|pair| pair[0].time() <= pair[1].time()))
+}
+
+#[then("the api successfully returns my balances")]
+async fn assert_balances_are_ok(world: &mut WorldImpl) {
+    let balances = world.balances.as_ref().unwrap().as_ref().unwrap();
+    assert!(balances.iter().all(|balance| balance.available() >= 0.0))
+}
+
+#[then("the api successfully returns my trade balance")]
+async fn assert_trade_balance_is_ok(world: &mut WorldImpl) {
+    let trade_balance = world.trade_balance.as_ref().unwrap().as_ref().unwrap();
+    let asset_pairs = world
+        .kraken_service
+        .retrieve_asset_pairs(&[String::from("XXBTZUSD")], AssetPairInfo::Margin)
+        .await
+        .unwrap();
+    let margins = asset_pairs["XXBTZUSD"].margins().unwrap();
+    assert_ne!(
+        trade_balance.margin_status(margins, 0),
+        MarginStatus::MarginStop
+    )
+}
+
+#[then("the api successfully returns my finished orders")]
+async fn assert_closed_orders_are_ok(world: &mut WorldImpl) {
+    let closed_orders = world.closed_orders.as_ref().unwrap();
+    assert!(closed_orders
+        .iter()
+        .all(|order| order.as_ref().unwrap().status().is_finished()))
+}
+
+#[then("the api successfully returns the orders I queried")]
+async fn assert_queried_orders_are_ok(world: &mut WorldImpl) {
+    let (txids, queried_orders) = world.queried_orders.as_ref().unwrap();
+    let identifiers = queried_orders
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|order| order.identifier().clone())
+        .collect::<Vec<String>>();
+    assert_eq!(&identifiers, txids)
+}
+
+#[then("the api successfully returns the trades of that order")]
+async fn assert_order_trades_are_ok(world: &mut WorldImpl) {
+    let (txids, trades) = world.order_trades.as_ref().unwrap();
+    let identifiers = trades
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|trade| trade.identifier().clone())
+        .collect::<Vec<String>>();
+    assert_eq!(&identifiers, txids)
+}
+
+#[then("the api successfully returns only trade ledger entries")]
+async fn assert_ledger_entries_are_ok(world: &mut WorldImpl) {
+    let ledger_entries = world.ledger_entries.as_ref().unwrap();
+    assert!(ledger_entries
+        .iter()
+        .all(|entry| entry.as_ref().unwrap().entry_type() == &LedgerEntryType::Trade))
+}
+
+#[then("the api successfully returns a fee consistent with the XBTUSD fee tiers")]
+async fn assert_trade_volume_is_ok(world: &mut WorldImpl) {
+    let trade_volume = world.trade_volume.as_ref().unwrap().as_ref().unwrap();
+    let asset_pairs = world
+        .kraken_service
+        .retrieve_asset_pairs(&[String::from("XXBTZUSD")], AssetPairInfo::Fees)
+        .await
+        .unwrap();
+    let fees = asset_pairs["XXBTZUSD"].fees().unwrap();
+    let status = trade_volume
+        .taker_tier_status("XXBTZUSD", fees.fees())
+        .unwrap();
+    assert!(status.matches_reported_fee())
+}
+
+#[then("the api successfully returns the order description without placing the order")]
+async fn check_validated_order(world: &mut WorldImpl) {
+    let confirmation = world.order_confirmation.as_ref().unwrap().as_ref().unwrap();
+    assert!(confirmation.validated_only());
+    assert!(confirmation.txids().is_empty());
+    assert!(confirmation.description().contains("limit"));
+}
+
+#[then("the api successfully returns a description for every order of the ladder")]
+async fn check_validated_order_ladder(world: &mut WorldImpl) {
+    let confirmations = world.batch_confirmations.as_ref().unwrap().as_ref().unwrap();
+    assert_eq!(confirmations.len(), 2);
+    for confirmation in confirmations {
+        assert!(confirmation.as_ref().unwrap().validated_only());
+    }
+}
+
+#[then("the service refuses to cancel them in validate-only mode")]
+async fn check_cancellation_refused(world: &mut WorldImpl) {
+    let cancellation = world.cancellation.as_ref().unwrap();
+    assert!(matches!(cancellation, Err(KrakenError::Validation(_))));
+}
+
+#[then("the service refuses to arm it in validate-only mode")]
+async fn check_dead_man_switch_refused(world: &mut WorldImpl) {
+    let keeper = world.dead_man_switch_keeper.as_ref().unwrap();
+    assert!(matches!(keeper, Err(KrakenError::Validation(_))));
+}
+
+#[then("the api successfully returns my open positions with their net value")]
+async fn assert_open_positions_are_ok(world: &mut WorldImpl) {
+    let open_positions = world.open_positions.as_ref().unwrap().as_ref().unwrap();
+    assert!(open_positions
+        .iter()
+        .all(|position| position.net().is_some() && position.open_volume() >= 0.0))
+}
+
+#[then("the api successfully returns at least one deposit method")]
+async fn assert_deposit_methods_are_ok(world: &mut WorldImpl) {
+    let deposit_methods = world.deposit_methods.as_ref().unwrap().as_ref().unwrap();
+    assert!(!deposit_methods.is_empty())
+}
+
+#[then("the api successfully returns my recent withdrawals")]
+async fn assert_withdrawals_are_ok(world: &mut WorldImpl) {
+    let withdrawals = world.withdrawals.as_ref().unwrap().as_ref().unwrap();
+    assert!(withdrawals
+        .iter()
+        .all(|withdrawal| withdrawal.asset().contains("XBT")))
+}
+
+#[then("the service refuses to make the transfer in validate-only mode")]
+async fn check_wallet_transfer_refused(world: &mut WorldImpl) {
+    let wallet_transfer = world.wallet_transfer.as_ref().unwrap();
+    assert!(matches!(wallet_transfer, Err(KrakenError::Validation(_))));
+}
+
+#[then("the api successfully returns only flexible strategies")]
+async fn assert_earn_strategies_are_flexible(world: &mut WorldImpl) {
+    let earn_strategies = world.earn_strategies.as_ref().unwrap().as_ref().unwrap();
+    assert!(earn_strategies
+        .iter()
+        .all(|strategy| strategy.lock_type() == LockType::Flex))
+}
+
+#[then("the api successfully returns only ledger exports")]
+async fn assert_ledger_exports_are_ok(world: &mut WorldImpl) {
+    let ledger_exports = world.ledger_exports.as_ref().unwrap().as_ref().unwrap();
+    assert!(ledger_exports
+        .iter()
+        .all(|export| export.report() == ReportType::Ledgers))
 }
 
 #[then("the api successfully returns a valid set of open orders")]
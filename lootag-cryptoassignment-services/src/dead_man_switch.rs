@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use lootag_cryptoassignment_domain::{
+    credentials::Credentials, dead_man_switch::DeadManSwitch, validation_error,
+};
+use tokio::{
+    sync::{oneshot, watch},
+    task::JoinHandle,
+    time,
+};
+
+use crate::{cancel_all_orders_after_impl, error::KrakenError, Configuration};
+
+#[derive(Debug)]
+pub struct DeadManSwitchKeeper {
+    stop: oneshot::Sender<()>,
+    status: watch::Receiver<Option<DeadManSwitch>>,
+    last_error: watch::Receiver<Option<KrakenError>>,
+    task: JoinHandle<Result<DeadManSwitch, KrakenError>>,
+}
+
+impl DeadManSwitchKeeper {
+    //The last timer kraken confirmed, None until the first arming succeeds
+    pub fn status(&self) -> Option<DeadManSwitch> {
+        self.status.borrow().clone()
+    }
+
+    //Why the latest arming failed, None once the timer is armed again
+    pub fn last_error(&self) -> Option<KrakenError> {
+        self.last_error.borrow().clone()
+    }
+
+    //Stopping on purpose disarms the timer, so the open orders survive a clean shutdown.
+    //If the keeper already gave up, the error that ended it is returned instead
+    pub async fn stop(self) -> Result<DeadManSwitch, KrakenError> {
+        let _ = self.stop.send(());
+        self.task
+            .await
+            .map_err(|e| KrakenError::Transport(e.to_string()))?
+    }
+}
+
+//A transient failure is retried on the next tick, since the timer armed before
+//it keeps running until then. Any other failure ends the keeper and leaves the
+//last armed timer to run out, as re-arming with the same request can't succeed.
+//Only an explicit stop disarms the timer, dropping the keeper leaves it armed
+pub(crate) fn spawn(
+    configuration: Configuration,
+    credentials: Credentials,
+    interval: Duration,
+    timeout: Duration,
+) -> DeadManSwitchKeeper {
+    let (stop_sender, mut stop_receiver) = oneshot::channel();
+    let (status_sender, status_receiver) = watch::channel(None);
+    let (error_sender, error_receiver) = watch::channel(None);
+    let task = tokio::spawn(async move {
+        let mut ticks = time::interval(interval);
+        loop {
+            tokio::select! {
+                _ = ticks.tick() => {
+                    let armed = cancel_all_orders_after_impl(&configuration, &credentials, timeout).await;
+                    match armed {
+                        Ok(dead_man_switch) => {
+                            let _ = status_sender.send(Some(dead_man_switch));
+                            let _ = error_sender.send(None);
+                        }
+                        Err(error) if error.is_transient() => {
+                            let _ = error_sender.send(Some(error));
+                        }
+                        Err(error) => {
+                            let _ = error_sender.send(Some(error.clone()));
+                            return Err(error);
+                        }
+                    }
+                }
+                stopped = &mut stop_receiver => match stopped {
+                    Ok(()) => break,
+                    Err(_) => {
+                        return Err(KrakenError::Validation(validation_error::new(
+                            "the keeper was dropped, the last armed timer keeps running",
+                        )))
+                    }
+                },
+            }
+        }
+        cancel_all_orders_after_impl(&configuration, &credentials, Duration::ZERO).await
+    });
+    DeadManSwitchKeeper {
+        stop: stop_sender,
+        status: status_receiver,
+        last_error: error_receiver,
+        task: task,
+    }
+}
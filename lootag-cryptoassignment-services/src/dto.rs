@@ -1,113 +1,516 @@
-use std::{
-    collections::{BTreeMap, HashMap},
-    io::ErrorKind,
-};
+use std::collections::{BTreeMap, HashMap};
 
+use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
 use lootag_cryptoassignment_domain::{
-    currency_amount,
+    asset_pair::{
+        self, AssetPair, AssetPairDetails, AssetPairFees, AssetPairLeverage, AssetPairMargins,
+    },
+    balance::{self, Balance, Balances},
+    candle::{self, Candle},
+    candle_series::{self, CandleSeries, Interval},
+    currency_amount::{self, CurrencyAmount},
+    dead_man_switch::{self, DeadManSwitch},
+    deposit::{self, Deposit, DepositAddress, DepositMethod},
+    earn::{self, EarnAllocation, EarnAllocations, EarnStrategy, LockType},
+    export::{self, Export, ExportFormat, ExportStatus, ReportType},
     fee_set::{self, FeeSet},
+    funding_status::{FundingStatus, FundingStatusProperty},
+    ledger_entry::{self, LedgerEntry, LedgerEntryType},
     leverage, margin,
     open_order::{self, OpenOrder, OrderStatus},
     open_order_description::{self, OpenOrderDescription, OrderType, Position},
+    order_book::{self, OrderBook, OrderBookLevel},
+    order_cancellation::{self, OrderCancellation},
+    order_confirmation::{self, OrderConfirmation},
+    order_edit::{self, OrderEditConfirmation},
+    position::{self as margin_position, Position as MarginPosition},
+    public_trade::{self, PublicTrade, PublicTradeSeries},
     server_time::{self, ServerTime},
-    xbt_usd::{self, XbtUsd},
+    spread::{self, Spread, SpreadSeries},
+    ticker::{self, Ticker, TickerQuote, TickerWindow},
+    trade::{self, Trade},
+    trade_balance::{self, TradeBalance},
+    trade_volume::{self, PairFee, TradeVolume},
+    transfer::{self, AccountTransferConfirmation},
+    withdrawal::{self, Withdrawal, WithdrawalInfo},
 };
 use serde;
 use serde::{Deserialize, Serialize};
 
+use crate::error::{self, KrakenError};
+
 #[derive(Deserialize)]
-pub(crate) struct ServerTimeDto {
+pub(crate) struct ResponseDto<T> {
     error: Vec<String>,
-    result: ServerTimeResult,
+    result: Option<T>,
 }
 
-impl ServerTimeDto {
-    pub(crate) fn to_business(&self) -> Result<ServerTime, std::io::Error> {
-        server_time::new(self.result.unixtime, self.result.rfc1123.clone())
+impl<T> ResponseDto<T> {
+    pub(crate) fn into_result(self) -> Result<T, KrakenError> {
+        match error::from_error_codes(&self.error) {
+            Some(error) => Err(error),
+            None => self
+                .result
+                .ok_or_else(|| KrakenError::Decoding(String::from("response has no result"))),
+        }
     }
 }
 
 #[derive(Deserialize)]
-pub(crate) struct ServerTimeResult {
+pub(crate) struct ServerTimeDto {
     unixtime: u64,
     rfc1123: String,
 }
 
+impl ServerTimeDto {
+    pub(crate) fn to_business(&self) -> Result<ServerTime, KrakenError> {
+        Ok(server_time::new(self.unixtime, self.rfc1123.clone())?)
+    }
+}
+
 #[derive(Deserialize)]
-pub(crate) struct XbtUsdPairDto {
-    error: Vec<String>,
-    result: XbtUsdPairResult,
+pub(crate) struct AssetPairDto {
+    altname: Option<String>,
+    wsname: Option<String>,
+    aclass_base: Option<String>,
+    base: Option<String>,
+    aclass_quote: Option<String>,
+    quote: Option<String>,
+    lot: Option<String>,
+    pair_decimals: Option<i32>,
+    lot_decimals: Option<i32>,
+    lot_multiplier: Option<i32>,
+    leverage_buy: Option<Vec<u32>>,
+    leverage_sell: Option<Vec<u32>>,
+    fees: Option<Vec<Vec<f32>>>,
+    fees_maker: Option<Vec<Vec<f32>>>,
+    fee_volume_currency: Option<String>,
+    margin_call: Option<u32>,
+    margin_stop: Option<u32>,
+    ordermin: Option<String>,
 }
 
-impl XbtUsdPairDto {
-    pub(crate) fn to_business(&self) -> Result<XbtUsd, std::io::Error> {
-        fn fees_to_business(fees: &Vec<Vec<f32>>) -> Result<FeeSet, std::io::Error> {
+impl AssetPairDto {
+    pub(crate) fn to_business(&self, name: &String) -> Result<AssetPair, KrakenError> {
+        Ok(asset_pair::new(
+            name.clone(),
+            self.details_to_business()?,
+            self.leverage_to_business()?,
+            self.fees_to_business()?,
+            self.margins_to_business()?,
+        )?)
+    }
+
+    fn details_to_business(&self) -> Result<Option<AssetPairDetails>, KrakenError> {
+        match (self.pair_decimals, self.lot_decimals, self.lot_multiplier) {
+            (Some(pair_decimals), Some(lot_decimals), Some(lot_multiplier)) => {
+                let details = asset_pair::new_details(
+                    self.altname.clone().unwrap_or_default(),
+                    self.wsname.clone().unwrap_or_default(),
+                    self.aclass_base.clone().unwrap_or_default(),
+                    self.base.clone().unwrap_or_default(),
+                    self.aclass_quote.clone().unwrap_or_default(),
+                    self.quote.clone().unwrap_or_default(),
+                    self.lot.clone().unwrap_or_default(),
+                    pair_decimals,
+                    lot_decimals,
+                    lot_multiplier,
+                    self.ordermin.clone().unwrap_or_default(),
+                )?;
+                Ok(Some(details))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn leverage_to_business(&self) -> Result<Option<AssetPairLeverage>, KrakenError> {
+        match (&self.leverage_buy, &self.leverage_sell) {
+            (Some(leverage_buy), Some(leverage_sell)) => Ok(Some(asset_pair::new_leverage(
+                leverage::new(leverage_buy.clone())?,
+                leverage::new(leverage_sell.clone())?,
+            )?)),
+            _ => Ok(None),
+        }
+    }
+
+    fn fees_to_business(&self) -> Result<Option<AssetPairFees>, KrakenError> {
+        fn fee_set_to_business(fees: &Vec<Vec<f32>>) -> Result<FeeSet, KrakenError> {
             let value = fees
                 .into_iter()
                 .map(|f| (f[0] as u32, f[1]))
                 .collect::<BTreeMap<u32, f32>>();
-            fee_set::new(value)
+            Ok(fee_set::new(value)?)
         }
-        xbt_usd::new(
-            self.result.XXBTZUSD.altname.clone(),
-            self.result.XXBTZUSD.wsname.clone(),
-            self.result.XXBTZUSD.aclass_base.clone(),
-            self.result.XXBTZUSD.base.clone(),
-            self.result.XXBTZUSD.aclass_quote.clone(),
-            self.result.XXBTZUSD.quote.clone(),
-            self.result.XXBTZUSD.lot.clone(),
-            self.result.XXBTZUSD.pair_decimals,
-            self.result.XXBTZUSD.lot_decimals,
-            self.result.XXBTZUSD.lot_multiplier,
-            leverage::new(self.result.XXBTZUSD.leverage_buy.clone())?,
-            leverage::new(self.result.XXBTZUSD.leverage_sell.clone())?,
-            fees_to_business(&self.result.XXBTZUSD.fees)?,
-            fees_to_business(&self.result.XXBTZUSD.fees_maker)?,
-            self.result.XXBTZUSD.fee_volume_currency.clone(),
-            margin::new(self.result.XXBTZUSD.margin_call)?,
-            margin::new(self.result.XXBTZUSD.margin_stop)?,
-            self.result.XXBTZUSD.ordermin.clone(),
-        )
-    }
-}
-
-#[derive(Deserialize)]
-pub(crate) struct XbtUsdPairResult {
-    XXBTZUSD: XbtUsdPair,
-}
-
-#[derive(Deserialize)]
-pub(crate) struct XbtUsdPair {
-    altname: String,
-    wsname: String,
-    aclass_base: String,
-    base: String,
-    aclass_quote: String,
-    quote: String,
-    lot: String,
-    pair_decimals: i32,
-    lot_decimals: i32,
-    lot_multiplier: i32,
-    leverage_buy: Vec<u32>,
-    leverage_sell: Vec<u32>,
-    fees: Vec<Vec<f32>>,
-    fees_maker: Vec<Vec<f32>>,
-    fee_volume_currency: String,
-    margin_call: u32,
-    margin_stop: u32,
-    ordermin: String,
+        match &self.fees {
+            Some(fees) => Ok(Some(asset_pair::new_fees(
+                fee_set_to_business(fees)?,
+                fee_set_to_business(&self.fees_maker.clone().unwrap_or_default())?,
+                self.fee_volume_currency.clone().unwrap_or_default(),
+            )?)),
+            None => Ok(None),
+        }
+    }
+
+    fn margins_to_business(&self) -> Result<Option<AssetPairMargins>, KrakenError> {
+        match (self.margin_call, self.margin_stop) {
+            (Some(margin_call), Some(margin_stop)) => Ok(Some(asset_pair::new_margins(
+                margin::new(margin_call)?,
+                margin::new(margin_stop)?,
+            )?)),
+            _ => Ok(None),
+        }
+    }
 }
 
 #[derive(Deserialize)]
-pub(crate) struct OpenOrdersDto {
-    error: Vec<String>,
-    pub(crate) result: OpenOrdersResult,
+pub(crate) struct TickerDto {
+    #[serde(rename(deserialize = "a"))]
+    ask: [String; 3],
+    #[serde(rename(deserialize = "b"))]
+    bid: [String; 3],
+    #[serde(rename(deserialize = "c"))]
+    last_trade: [String; 2],
+    #[serde(rename(deserialize = "v"))]
+    volume: [String; 2],
+    #[serde(rename(deserialize = "p"))]
+    vwap: [String; 2],
+    #[serde(rename(deserialize = "t"))]
+    trade_count: [u32; 2],
+    #[serde(rename(deserialize = "l"))]
+    low: [String; 2],
+    #[serde(rename(deserialize = "h"))]
+    high: [String; 2],
+    #[serde(rename(deserialize = "o"))]
+    open: String,
+}
+
+impl TickerDto {
+    pub(crate) fn to_business(&self, pair: &String) -> Result<Ticker, KrakenError> {
+        Ok(ticker::new(
+            pair.clone(),
+            self.quote_to_business(&self.ask)?,
+            self.quote_to_business(&self.bid)?,
+            ticker::new_trade(
+                currency_amount::new(string_to_f32(&self.last_trade[0])?)?,
+                currency_amount::new(string_to_f32(&self.last_trade[1])?)?,
+            )?,
+            self.window_to_business(&self.volume)?,
+            self.window_to_business(&self.vwap)?,
+            ticker::new_window(self.trade_count[0], self.trade_count[1])?,
+            self.window_to_business(&self.low)?,
+            self.window_to_business(&self.high)?,
+            currency_amount::new(string_to_f32(&self.open)?)?,
+        )?)
+    }
+
+    fn quote_to_business(&self, quote: &[String; 3]) -> Result<TickerQuote, KrakenError> {
+        Ok(ticker::new_quote(
+            currency_amount::new(string_to_f32(&quote[0])?)?,
+            currency_amount::new(string_to_f32(&quote[1])?)?,
+            currency_amount::new(string_to_f32(&quote[2])?)?,
+        )?)
+    }
+
+    fn window_to_business(
+        &self,
+        window: &[String; 2],
+    ) -> Result<TickerWindow<CurrencyAmount>, KrakenError> {
+        Ok(ticker::new_window(
+            currency_amount::new(string_to_f32(&window[0])?)?,
+            currency_amount::new(string_to_f32(&window[1])?)?,
+        )?)
+    }
 }
 
 #[derive(Deserialize)]
-pub(crate) struct OpenOrdersResult {
+pub(crate) struct OhlcDto {
+    last: u64,
     #[serde(flatten)]
-    pub(crate) open: Option<HashMap<String, OpenOrderDto>>,
+    series: HashMap<String, Vec<CandleDto>>,
+}
+
+impl OhlcDto {
+    pub(crate) fn to_business(&self, interval: Interval) -> Result<CandleSeries, KrakenError> {
+        let (pair, candles) = self
+            .series
+            .iter()
+            .next()
+            .ok_or_else(|| KrakenError::Decoding(String::from("missing ohlc series")))?;
+        Ok(candle_series::new(
+            pair.clone(),
+            interval,
+            candles
+                .iter()
+                .map(|c| c.to_business())
+                .collect::<Result<Vec<Candle>, KrakenError>>()?,
+            self.last,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CandleDto(u64, String, String, String, String, String, String, u32);
+
+impl CandleDto {
+    pub(crate) fn to_business(&self) -> Result<Candle, KrakenError> {
+        Ok(candle::new(
+            self.0,
+            currency_amount::new(string_to_f32(&self.1)?)?,
+            currency_amount::new(string_to_f32(&self.2)?)?,
+            currency_amount::new(string_to_f32(&self.3)?)?,
+            currency_amount::new(string_to_f32(&self.4)?)?,
+            currency_amount::new(string_to_f32(&self.5)?)?,
+            currency_amount::new(string_to_f32(&self.6)?)?,
+            self.7,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrderBookDto {
+    bids: Vec<OrderBookLevelDto>,
+    asks: Vec<OrderBookLevelDto>,
+}
+
+impl OrderBookDto {
+    pub(crate) fn to_business(&self, pair: &String) -> Result<OrderBook, KrakenError> {
+        Ok(order_book::new(
+            pair.clone(),
+            self.bids
+                .iter()
+                .map(|b| b.to_business())
+                .collect::<Result<Vec<OrderBookLevel>, KrakenError>>()?,
+            self.asks
+                .iter()
+                .map(|a| a.to_business())
+                .collect::<Result<Vec<OrderBookLevel>, KrakenError>>()?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OrderBookLevelDto(String, String, u64);
+
+impl OrderBookLevelDto {
+    pub(crate) fn to_business(&self) -> Result<OrderBookLevel, KrakenError> {
+        Ok(order_book::new_level(
+            currency_amount::new(string_to_f32(&self.0)?)?,
+            currency_amount::new(string_to_f32(&self.1)?)?,
+            self.2,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct PublicTradesDto {
+    last: String,
+    #[serde(flatten)]
+    series: HashMap<String, Vec<PublicTradeDto>>,
+}
+
+impl PublicTradesDto {
+    pub(crate) fn to_business(&self) -> Result<PublicTradeSeries, KrakenError> {
+        let (pair, trades) = self
+            .series
+            .iter()
+            .next()
+            .ok_or_else(|| KrakenError::Decoding(String::from("missing trades series")))?;
+        Ok(public_trade::new_series(
+            pair.clone(),
+            trades
+                .iter()
+                .map(|t| t.to_business())
+                .collect::<Result<Vec<PublicTrade>, KrakenError>>()?,
+            self.last
+                .parse::<u64>()
+                .map_err(|e| KrakenError::Decoding(e.to_string()))?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct PublicTradeDto(String, String, f64, String, String, String, u64);
+
+impl PublicTradeDto {
+    pub(crate) fn to_business(&self) -> Result<PublicTrade, KrakenError> {
+        Ok(public_trade::new(
+            currency_amount::new(string_to_f32(&self.0)?)?,
+            currency_amount::new(string_to_f32(&self.1)?)?,
+            self.2,
+            self.string_to_position(&self.3)?,
+            self.string_to_order_type(&self.4)?,
+            self.5.clone(),
+            self.6,
+        )?)
+    }
+
+    fn string_to_position(&self, position: &String) -> Result<Position, KrakenError> {
+        match position.as_str() {
+            "b" => Ok(Position::Buy),
+            "s" => Ok(Position::Sell),
+            _ => Err(KrakenError::Decoding(String::from("unknown position"))),
+        }
+    }
+
+    fn string_to_order_type(&self, order_type: &String) -> Result<OrderType, KrakenError> {
+        match order_type.as_str() {
+            "m" => Ok(OrderType::Market),
+            "l" => Ok(OrderType::Limit),
+            _ => Err(KrakenError::Decoding(String::from("unknown order type"))),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SpreadsDto {
+    last: u64,
+    #[serde(flatten)]
+    series: HashMap<String, Vec<SpreadDto>>,
+}
+
+impl SpreadsDto {
+    pub(crate) fn to_business(&self) -> Result<SpreadSeries, KrakenError> {
+        let (pair, spreads) = self
+            .series
+            .iter()
+            .next()
+            .ok_or_else(|| KrakenError::Decoding(String::from("missing spread series")))?;
+        Ok(spread::new_series(
+            pair.clone(),
+            spreads
+                .iter()
+                .map(|s| s.to_business())
+                .collect::<Result<Vec<Spread>, KrakenError>>()?,
+            self.last,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SpreadDto(u64, String, String);
+
+impl SpreadDto {
+    pub(crate) fn to_business(&self) -> Result<Spread, KrakenError> {
+        Ok(spread::new(
+            self.0,
+            currency_amount::new(string_to_f32(&self.1)?)?,
+            currency_amount::new(string_to_f32(&self.2)?)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BalancesDto(HashMap<String, String>);
+
+impl BalancesDto {
+    pub(crate) fn to_business(&self) -> Result<Balances, KrakenError> {
+        Ok(balance::new_balances(
+            self.0
+                .iter()
+                .map(|(asset, amount)| {
+                    Ok(balance::new(
+                        asset.clone(),
+                        currency_amount::new(string_to_f32(amount)?)?,
+                        None,
+                    )?)
+                })
+                .collect::<Result<Vec<Balance>, KrakenError>>()?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ExtendedBalancesDto(HashMap<String, ExtendedBalanceDto>);
+
+#[derive(Deserialize)]
+pub(crate) struct ExtendedBalanceDto {
+    balance: String,
+    hold_trade: Option<String>,
+}
+
+impl ExtendedBalancesDto {
+    pub(crate) fn to_business(&self) -> Result<Balances, KrakenError> {
+        Ok(balance::new_balances(
+            self.0
+                .iter()
+                .map(|(asset, balance)| balance.to_business(asset))
+                .collect::<Result<Vec<Balance>, KrakenError>>()?,
+        )?)
+    }
+}
+
+impl ExtendedBalanceDto {
+    pub(crate) fn to_business(&self, asset: &String) -> Result<Balance, KrakenError> {
+        let hold_trade = match &self.hold_trade {
+            Some(hold_trade) => Some(currency_amount::new(string_to_f32(hold_trade)?)?),
+            None => None,
+        };
+        Ok(balance::new(
+            asset.clone(),
+            currency_amount::new(string_to_f32(&self.balance)?)?,
+            hold_trade,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct TradeBalanceDto {
+    #[serde(rename(deserialize = "eb"))]
+    equivalent_balance: String,
+    #[serde(rename(deserialize = "tb"))]
+    trade_balance: String,
+    #[serde(rename(deserialize = "m"))]
+    margin_used: String,
+    #[serde(rename(deserialize = "n"))]
+    unrealised_pnl: String,
+    #[serde(rename(deserialize = "c"))]
+    cost_basis: String,
+    #[serde(rename(deserialize = "v"))]
+    floating_valuation: String,
+    #[serde(rename(deserialize = "e"))]
+    equity: String,
+    #[serde(rename(deserialize = "mf"))]
+    free_margin: String,
+    #[serde(rename(deserialize = "ml"))]
+    margin_level: Option<String>,
+    #[serde(rename(deserialize = "uv"))]
+    unexecuted_value: Option<String>,
+}
+
+impl TradeBalanceDto {
+    pub(crate) fn to_business(&self) -> Result<TradeBalance, KrakenError> {
+        let margin_level = match &self.margin_level {
+            Some(margin_level) => Some(string_to_f32(margin_level)?),
+            None => None,
+        };
+        let unexecuted_value = match &self.unexecuted_value {
+            Some(unexecuted_value) => Some(currency_amount::new(string_to_f32(unexecuted_value)?)?),
+            None => None,
+        };
+        Ok(trade_balance::new(
+            currency_amount::new(string_to_f32(&self.equivalent_balance)?)?,
+            currency_amount::new(string_to_f32(&self.trade_balance)?)?,
+            currency_amount::new(string_to_f32(&self.margin_used)?)?,
+            string_to_f32(&self.unrealised_pnl)?,
+            currency_amount::new(string_to_f32(&self.cost_basis)?)?,
+            currency_amount::new(string_to_f32(&self.floating_valuation)?)?,
+            currency_amount::new(string_to_f32(&self.equity)?)?,
+            string_to_f32(&self.free_margin)?,
+            margin_level,
+            unexecuted_value,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct OpenOrdersDto {
+    pub(crate) open: HashMap<String, OpenOrderDto>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ClosedOrdersDto {
+    pub(crate) closed: HashMap<String, OpenOrderDto>,
+    pub(crate) count: u32,
 }
 
 #[derive(Deserialize)]
@@ -115,9 +518,10 @@ pub(crate) struct OpenOrderDto {
     refid: Option<String>,
     userref: u32,
     status: String,
-    opentm: f32,
-    starttm: f32,
-    expiretm: f32,
+    opentm: f64,
+    starttm: f64,
+    expiretm: f64,
+    closetm: Option<f64>,
     descr: OpenOrderDescrDto,
     vol: String,
     vol_exec: String,
@@ -128,12 +532,14 @@ pub(crate) struct OpenOrderDto {
     limitprice: String,
     misc: String,
     oflags: String,
+    #[serde(default)]
     trades: Vec<String>,
+    reason: Option<String>,
 }
 
 impl OpenOrderDto {
-    pub(crate) fn to_business(&self, identifier: &String) -> Result<OpenOrder, std::io::Error> {
-        open_order::new(
+    pub(crate) fn to_business(&self, identifier: &String) -> Result<OpenOrder, KrakenError> {
+        Ok(open_order::new(
             identifier.clone(),
             self.refid.clone(),
             self.userref,
@@ -141,6 +547,7 @@ impl OpenOrderDto {
             self.opentm,
             self.starttm,
             self.expiretm,
+            self.closetm,
             self.descr.to_business()?,
             currency_amount::new(string_to_f32(&self.vol)?)?,
             currency_amount::new(string_to_f32(&self.vol_exec)?)?,
@@ -152,29 +559,897 @@ impl OpenOrderDto {
             self.misc.clone(),
             self.oflags.clone(),
             self.trades.clone(),
-        )
-    }
-
-    fn string_to_order_status(&self, status: &String) -> Result<OrderStatus, std::io::Error> {
-        let open_string = String::from("open");
-        let closed_string = String::from("closed");
-        if status == &open_string {
-            Ok(OrderStatus::Open)
-        } else if status == &closed_string {
-            Ok(OrderStatus::Closed)
-        } else {
-            Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                "unknown order status",
-            ))
+            self.reason.clone(),
+        )?)
+    }
+
+    fn string_to_order_status(&self, status: &String) -> Result<OrderStatus, KrakenError> {
+        match status.as_str() {
+            "pending" => Ok(OrderStatus::Pending),
+            "open" => Ok(OrderStatus::Open),
+            "closed" => Ok(OrderStatus::Closed),
+            "canceled" => Ok(OrderStatus::Canceled),
+            "expired" => Ok(OrderStatus::Expired),
+            _ => Err(KrakenError::Decoding(String::from("unknown order status"))),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct TradesHistoryDto {
+    pub(crate) trades: HashMap<String, TradeDto>,
+    pub(crate) count: u32,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct TradeDto {
+    ordertxid: String,
+    postxid: Option<String>,
+    pair: String,
+    time: f64,
+    #[serde(rename(deserialize = "type"))]
+    position: String,
+    ordertype: String,
+    price: String,
+    cost: String,
+    fee: String,
+    vol: String,
+    margin: String,
+    #[serde(default)]
+    maker: Option<bool>,
+    misc: String,
+}
+
+impl TradeDto {
+    pub(crate) fn to_business(&self, identifier: &String) -> Result<Trade, KrakenError> {
+        Ok(trade::new(
+            identifier.clone(),
+            self.ordertxid.clone(),
+            self.postxid.clone(),
+            self.pair.clone(),
+            self.time,
+            string_to_position(&self.position)?,
+            string_to_order_type(&self.ordertype)?,
+            string_to_f64(&self.price)?,
+            string_to_f64(&self.cost)?,
+            string_to_f64(&self.fee)?,
+            string_to_f64(&self.vol)?,
+            string_to_f64(&self.margin)?,
+            self.maker,
+            self.misc.clone(),
+        )?)
+    }
+}
+
+//Positions consolidated by market have no txid, order txid or rollover time
+#[derive(Deserialize)]
+pub(crate) struct OpenPositionDto {
+    ordertxid: Option<String>,
+    pair: String,
+    #[serde(rename(deserialize = "type"))]
+    position: String,
+    cost: String,
+    fee: String,
+    vol: String,
+    vol_closed: String,
+    margin: String,
+    value: Option<String>,
+    net: Option<String>,
+    rollovertm: Option<String>,
+}
+
+impl OpenPositionDto {
+    pub(crate) fn to_business(
+        &self,
+        identifier: Option<&String>,
+    ) -> Result<MarginPosition, KrakenError> {
+        let value = match &self.value {
+            Some(value) => Some(currency_amount::new(string_to_f32(value)?)?),
+            None => None,
+        };
+        let net = match &self.net {
+            Some(net) => Some(string_to_f32(net)?),
+            None => None,
+        };
+        let rollover_time = match &self.rollovertm {
+            Some(rollover_time) => Some(
+                rollover_time
+                    .parse::<u64>()
+                    .map_err(|e| KrakenError::Decoding(e.to_string()))?,
+            ),
+            None => None,
+        };
+        Ok(margin_position::new(
+            identifier.cloned(),
+            self.ordertxid.clone(),
+            self.pair.clone(),
+            string_to_position(&self.position)?,
+            currency_amount::new(string_to_f32(&self.cost)?)?,
+            currency_amount::new(string_to_f32(&self.fee)?)?,
+            currency_amount::new(string_to_f32(&self.vol)?)?,
+            currency_amount::new(string_to_f32(&self.vol_closed)?)?,
+            currency_amount::new(string_to_f32(&self.margin)?)?,
+            value,
+            net,
+            rollover_time,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct LedgersDto {
+    pub(crate) ledger: HashMap<String, LedgerEntryDto>,
+    pub(crate) count: u32,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct LedgerEntryDto {
+    refid: String,
+    time: f64,
+    #[serde(rename(deserialize = "type"))]
+    entry_type: String,
+    subtype: String,
+    aclass: String,
+    asset: String,
+    amount: String,
+    fee: String,
+    balance: String,
+}
+
+impl LedgerEntryDto {
+    pub(crate) fn to_business(&self, identifier: &String) -> Result<LedgerEntry, KrakenError> {
+        Ok(ledger_entry::new(
+            identifier.clone(),
+            self.refid.clone(),
+            self.time,
+            LedgerEntryType::from_value(&self.entry_type)?,
+            self.subtype.clone(),
+            self.aclass.clone(),
+            self.asset.clone(),
+            string_to_f64(&self.amount)?,
+            string_to_f64(&self.fee)?,
+            string_to_f64(&self.balance)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct TradeVolumeDto {
+    currency: String,
+    volume: String,
+    #[serde(default)]
+    fees: HashMap<String, PairFeeDto>,
+    #[serde(default)]
+    fees_maker: HashMap<String, PairFeeDto>,
+}
+
+impl TradeVolumeDto {
+    pub(crate) fn to_business(&self) -> Result<TradeVolume, KrakenError> {
+        fn pair_fees_to_business(
+            fees: &HashMap<String, PairFeeDto>,
+        ) -> Result<HashMap<String, PairFee>, KrakenError> {
+            fees.iter()
+                .map(|(pair, fee)| Ok((pair.clone(), fee.to_business()?)))
+                .collect::<Result<HashMap<String, PairFee>, KrakenError>>()
+        }
+        Ok(trade_volume::new(
+            self.currency.clone(),
+            currency_amount::new(string_to_f32(&self.volume)?)?,
+            pair_fees_to_business(&self.fees)?,
+            pair_fees_to_business(&self.fees_maker)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct PairFeeDto {
+    fee: String,
+    minfee: String,
+    maxfee: String,
+    nextfee: Option<String>,
+    tiervolume: Option<String>,
+    nextvolume: Option<String>,
+}
+
+impl PairFeeDto {
+    pub(crate) fn to_business(&self) -> Result<PairFee, KrakenError> {
+        Ok(trade_volume::new_pair_fee(
+            string_to_f32(&self.fee)?,
+            string_to_f32(&self.minfee)?,
+            string_to_f32(&self.maxfee)?,
+            optional_string_to_f32(&self.nextfee)?,
+            optional_string_to_f32(&self.tiervolume)?,
+            optional_string_to_f32(&self.nextvolume)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AddOrderDto {
+    descr: AddOrderDescrDto,
+    #[serde(default)]
+    txid: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AddOrderDescrDto {
+    order: String,
+    close: Option<String>,
+}
+
+impl AddOrderDto {
+    pub(crate) fn to_business(&self, validate: bool) -> Result<OrderConfirmation, KrakenError> {
+        if validate {
+            return Ok(order_confirmation::new_validated(
+                self.descr.order.clone(),
+                self.descr.close.clone(),
+            ));
+        }
+        Ok(order_confirmation::new(
+            self.descr.order.clone(),
+            self.descr.close.clone(),
+            self.txid.clone(),
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AddOrderBatchDto {
+    orders: Vec<BatchOrderDto>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct BatchOrderDto {
+    descr: Option<AddOrderDescrDto>,
+    txid: Option<String>,
+    error: Option<String>,
+}
+
+impl AddOrderBatchDto {
+    pub(crate) fn to_business(
+        &self,
+        validate: bool,
+    ) -> Vec<Result<OrderConfirmation, KrakenError>> {
+        self.orders
+            .iter()
+            .map(|order| order.to_business(validate))
+            .collect()
+    }
+}
+
+//Kraken reports the orders it rejected alongside the ones it accepted, so a
+//rejection only fails its own entry of the batch
+impl BatchOrderDto {
+    fn to_business(&self, validate: bool) -> Result<OrderConfirmation, KrakenError> {
+        if let Some(code) = &self.error {
+            return Err(KrakenError::Exchange(error::parse_exchange_error(code)));
+        }
+        let descr = self
+            .descr
+            .as_ref()
+            .ok_or_else(|| KrakenError::Decoding(String::from("batch order has no description")))?;
+        if validate {
+            return Ok(order_confirmation::new_validated(
+                descr.order.clone(),
+                descr.close.clone(),
+            ));
         }
+        Ok(order_confirmation::new(
+            descr.order.clone(),
+            descr.close.clone(),
+            self.txid.iter().cloned().collect(),
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EditOrderDto {
+    descr: Option<AddOrderDescrDto>,
+    txid: Option<String>,
+    originaltxid: Option<String>,
+    status: Option<String>,
+    error_message: Option<String>,
+}
+
+//EditOrder reports a failed edit through its status rather than the error array
+impl EditOrderDto {
+    pub(crate) fn to_business(&self, validate: bool) -> Result<OrderEditConfirmation, KrakenError> {
+        if self.status.as_deref() == Some("err") {
+            let code = self.error_message.clone().unwrap_or_default();
+            return Err(KrakenError::Exchange(error::parse_exchange_error(&code)));
+        }
+        let description = self
+            .descr
+            .as_ref()
+            .map(|descr| descr.order.clone())
+            .ok_or_else(|| {
+                KrakenError::Decoding(String::from("edited order has no description"))
+            })?;
+        if validate {
+            return Ok(order_edit::new_validated_edit_confirmation(
+                description,
+                self.originaltxid.clone(),
+            ));
+        }
+        Ok(order_edit::new_edit_confirmation(
+            description,
+            self.txid.clone().unwrap_or_default(),
+            self.originaltxid.clone().unwrap_or_default(),
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AmendOrderDto {
+    amend_id: String,
+}
+
+impl AmendOrderDto {
+    pub(crate) fn to_business(&self, txid: String) -> Result<OrderEditConfirmation, KrakenError> {
+        Ok(order_edit::new_amend_confirmation(
+            self.amend_id.clone(),
+            txid,
+        )?)
+    }
+}
+
+//CancelOrderBatch and CancelAll only report a count, never pending orders
+#[derive(Deserialize)]
+pub(crate) struct CancelOrderDto {
+    count: u32,
+    #[serde(default)]
+    pending: bool,
+}
+
+impl CancelOrderDto {
+    pub(crate) fn to_business(&self) -> OrderCancellation {
+        order_cancellation::new(self.count, self.pending)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct CancelAllOrdersAfterDto {
+    #[serde(rename(deserialize = "currentTime"))]
+    current_time: String,
+    #[serde(rename(deserialize = "triggerTime"))]
+    trigger_time: String,
+}
+
+//A disarmed timer reports its trigger time as 0
+impl CancelAllOrdersAfterDto {
+    pub(crate) fn to_business(&self) -> Result<DeadManSwitch, KrakenError> {
+        let trigger_time = match self.trigger_time.as_str() {
+            "0" => None,
+            trigger_time => Some(string_to_date_time(trigger_time)?),
+        };
+        Ok(dead_man_switch::new(
+            string_to_date_time(&self.current_time)?,
+            trigger_time,
+        )?)
+    }
+}
+
+fn string_to_date_time(string: &str) -> Result<DateTime<Utc>, KrakenError> {
+    DateTime::parse_from_rfc3339(string)
+        .map(|date_time| date_time.with_timezone(&Utc))
+        .map_err(|e| KrakenError::Decoding(e.to_string()))
+}
+
+#[derive(Deserialize)]
+pub(crate) struct DepositMethodDto {
+    method: String,
+    limit: DepositLimitDto,
+    fee: Option<String>,
+    #[serde(rename(deserialize = "address-setup-fee"))]
+    address_setup_fee: Option<String>,
+    #[serde(rename(deserialize = "gen-address"), default)]
+    gen_address: bool,
+    minimum: Option<String>,
+}
+
+//Kraken sends false instead of an amount when a method has no limit
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum DepositLimitDto {
+    Flag(bool),
+    Amount(String),
+}
+
+impl DepositMethodDto {
+    pub(crate) fn to_business(&self) -> Result<DepositMethod, KrakenError> {
+        let limit = match &self.limit {
+            DepositLimitDto::Flag(false) => None,
+            DepositLimitDto::Flag(true) => {
+                return Err(KrakenError::Decoding(String::from(
+                    "a deposit limit needs an amount",
+                )))
+            }
+            DepositLimitDto::Amount(limit) => Some(string_to_f32(limit)?),
+        };
+        Ok(deposit::new_method(
+            self.method.clone(),
+            limit,
+            optional_string_to_currency_amount(&self.fee)?,
+            optional_string_to_currency_amount(&self.address_setup_fee)?,
+            self.gen_address,
+            optional_string_to_currency_amount(&self.minimum)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct DepositAddressDto {
+    address: String,
+    expiretm: String,
+    #[serde(default)]
+    new: bool,
+    tag: Option<String>,
+}
+
+//Addresses that never expire report an expire time of 0
+impl DepositAddressDto {
+    pub(crate) fn to_business(&self) -> Result<DepositAddress, KrakenError> {
+        let expire_time = match self.expiretm.as_str() {
+            "0" => None,
+            expiretm => Some(
+                expiretm
+                    .parse::<u64>()
+                    .map_err(|e| KrakenError::Decoding(e.to_string()))?,
+            ),
+        };
+        Ok(deposit::new_address(
+            self.address.clone(),
+            expire_time,
+            self.new,
+            self.tag.clone(),
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct DepositDto {
+    method: String,
+    aclass: String,
+    asset: String,
+    refid: String,
+    txid: String,
+    info: String,
+    amount: String,
+    fee: Option<String>,
+    time: u64,
+    status: String,
+    #[serde(rename(deserialize = "status-prop"))]
+    status_prop: Option<String>,
+}
+
+impl DepositDto {
+    pub(crate) fn to_business(&self) -> Result<Deposit, KrakenError> {
+        Ok(deposit::new(
+            self.method.clone(),
+            self.aclass.clone(),
+            self.asset.clone(),
+            self.refid.clone(),
+            self.txid.clone(),
+            self.info.clone(),
+            currency_amount::new(string_to_f32(&self.amount)?)?,
+            currency_amount::new(optional_string_to_f32(&self.fee)?.unwrap_or(0.0))?,
+            self.time,
+            FundingStatus::from_value(&self.status)?,
+            optional_string_to_status_property(&self.status_prop)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WithdrawInfoDto {
+    method: String,
+    limit: String,
+    amount: String,
+    fee: String,
+}
+
+impl WithdrawInfoDto {
+    pub(crate) fn to_business(
+        &self,
+        asset: &String,
+        key: &String,
+    ) -> Result<WithdrawalInfo, KrakenError> {
+        Ok(withdrawal::new_info(
+            asset.clone(),
+            key.clone(),
+            self.method.clone(),
+            string_to_f64(&self.limit)?,
+            string_to_f64(&self.amount)?,
+            string_to_f64(&self.fee)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WithdrawDto {
+    pub(crate) refid: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WithdrawalDto {
+    method: String,
+    network: Option<String>,
+    aclass: String,
+    asset: String,
+    refid: String,
+    txid: Option<String>,
+    info: String,
+    amount: String,
+    fee: Option<String>,
+    time: u64,
+    status: String,
+    #[serde(rename(deserialize = "status-prop"))]
+    status_prop: Option<String>,
+}
+
+impl WithdrawalDto {
+    pub(crate) fn to_business(&self) -> Result<Withdrawal, KrakenError> {
+        Ok(withdrawal::new(
+            self.method.clone(),
+            self.network.clone(),
+            self.aclass.clone(),
+            self.asset.clone(),
+            self.refid.clone(),
+            self.txid.clone(),
+            self.info.clone(),
+            string_to_f64(&self.amount)?,
+            optional_string_to_f64(&self.fee)?.unwrap_or(0.0),
+            self.time,
+            FundingStatus::from_value(&self.status)?,
+            optional_string_to_status_property(&self.status_prop)?,
+        )?)
+    }
+}
+
+fn optional_string_to_status_property(
+    string: &Option<String>,
+) -> Result<Option<FundingStatusProperty>, KrakenError> {
+    match string {
+        Some(string) => Ok(Some(FundingStatusProperty::from_value(string)?)),
+        None => Ok(None),
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct WalletTransferDto {
+    pub(crate) refid: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AccountTransferDto {
+    transfer_id: String,
+    status: String,
+}
+
+impl AccountTransferDto {
+    pub(crate) fn to_business(&self) -> AccountTransferConfirmation {
+        transfer::new_account_transfer_confirmation(self.transfer_id.clone(), self.status.clone())
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EarnStrategiesDto {
+    pub(crate) items: Vec<EarnStrategyDto>,
+    pub(crate) next_cursor: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EarnStrategyDto {
+    id: String,
+    asset: String,
+    lock_type: EarnLockTypeDto,
+    apr_estimate: Option<AprEstimateDto>,
+    user_min_allocation: Option<String>,
+    can_allocate: bool,
+    can_deallocate: bool,
+}
+
+//The lock type also carries bonding and payout periods, only its type is kept
+#[derive(Deserialize)]
+pub(crate) struct EarnLockTypeDto {
+    #[serde(rename(deserialize = "type"))]
+    lock_type: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AprEstimateDto {
+    low: String,
+    high: String,
+}
+
+impl EarnStrategyDto {
+    pub(crate) fn to_business(&self) -> Result<EarnStrategy, KrakenError> {
+        let apr = match &self.apr_estimate {
+            Some(apr_estimate) => Some(earn::new_apr_range(
+                string_to_f32(&apr_estimate.low)?,
+                string_to_f32(&apr_estimate.high)?,
+            )?),
+            None => None,
+        };
+        Ok(earn::new_strategy(
+            self.id.clone(),
+            self.asset.clone(),
+            LockType::from_value(&self.lock_type.lock_type)?,
+            apr,
+            optional_string_to_f64(&self.user_min_allocation)?,
+            self.can_allocate,
+            self.can_deallocate,
+        ))
     }
 }
 
-fn string_to_f32(string: &String) -> Result<f32, std::io::Error> {
+#[derive(Deserialize)]
+pub(crate) struct EarnAllocationsDto {
+    converted_asset: String,
+    total_allocated: String,
+    total_rewarded: String,
+    items: Vec<EarnAllocationDto>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EarnAllocationDto {
+    strategy_id: String,
+    native_asset: String,
+    amount_allocated: EarnAllocatedAmountDto,
+    total_rewarded: EarnAmountDto,
+}
+
+//The allocated amount is also broken down by bonding state, only the total is kept
+#[derive(Deserialize)]
+pub(crate) struct EarnAllocatedAmountDto {
+    total: EarnAmountDto,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EarnAmountDto {
+    native: String,
+    converted: String,
+}
+
+impl EarnAllocationsDto {
+    pub(crate) fn to_business(&self) -> Result<EarnAllocations, KrakenError> {
+        let allocations = self
+            .items
+            .iter()
+            .map(|allocation| allocation.to_business())
+            .collect::<Result<Vec<EarnAllocation>, KrakenError>>()?;
+        Ok(earn::new_allocations(
+            self.converted_asset.clone(),
+            currency_amount::new(string_to_f32(&self.total_allocated)?)?,
+            currency_amount::new(string_to_f32(&self.total_rewarded)?)?,
+            allocations,
+        ))
+    }
+}
+
+impl EarnAllocationDto {
+    pub(crate) fn to_business(&self) -> Result<EarnAllocation, KrakenError> {
+        let allocated = &self.amount_allocated.total;
+        Ok(earn::new_allocation(
+            self.strategy_id.clone(),
+            self.native_asset.clone(),
+            currency_amount::new(string_to_f32(&allocated.native)?)?,
+            currency_amount::new(string_to_f32(&allocated.converted)?)?,
+            currency_amount::new(string_to_f32(&self.total_rewarded.native)?)?,
+            currency_amount::new(string_to_f32(&self.total_rewarded.converted)?)?,
+        ))
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct EarnStatusDto {
+    pub(crate) pending: bool,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct AddExportDto {
+    pub(crate) id: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ExportDto {
+    id: String,
+    descr: String,
+    format: String,
+    report: String,
+    status: String,
+    createdtm: String,
+    completedtm: String,
+    expiretm: String,
+    datastarttm: String,
+    dataendtm: String,
+}
+
+//The completed time is 0 until the export has been processed
+impl ExportDto {
+    pub(crate) fn to_business(&self) -> Result<Export, KrakenError> {
+        let completed_time = match string_to_u64(&self.completedtm)? {
+            0 => None,
+            completed_time => Some(completed_time),
+        };
+        Ok(export::new(
+            self.id.clone(),
+            self.descr.clone(),
+            ReportType::from_value(&self.report)?,
+            ExportFormat::from_value(&self.format)?,
+            ExportStatus::from_value(&self.status)?,
+            string_to_u64(&self.createdtm)?,
+            completed_time,
+            string_to_u64(&self.expiretm)?,
+            string_to_u64(&self.datastarttm)?,
+            string_to_u64(&self.dataendtm)?,
+        )?)
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RemoveExportDto {
+    #[serde(default)]
+    delete: bool,
+    #[serde(default)]
+    cancel: bool,
+}
+
+impl RemoveExportDto {
+    pub(crate) fn to_business(&self) -> bool {
+        self.delete || self.cancel
+    }
+}
+
+//A row of a trades export, where times are dates rather than timestamps
+#[derive(Deserialize)]
+pub(crate) struct TradeReportDto {
+    txid: String,
+    ordertxid: String,
+    #[serde(default)]
+    postxid: Option<String>,
+    pair: String,
+    time: String,
+    #[serde(rename(deserialize = "type"))]
+    position: String,
+    ordertype: String,
+    price: String,
+    cost: String,
+    fee: String,
+    vol: String,
+    margin: String,
+    #[serde(default)]
+    misc: String,
+}
+
+impl TradeReportDto {
+    pub(crate) fn to_business(&self) -> Result<Trade, KrakenError> {
+        Ok(trade::new(
+            self.txid.clone(),
+            self.ordertxid.clone(),
+            self.postxid.clone(),
+            self.pair.clone(),
+            report_time_to_f64(&self.time)?,
+            string_to_position(&self.position)?,
+            string_to_order_type(&self.ordertype)?,
+            string_to_f64(&self.price)?,
+            string_to_f64(&self.cost)?,
+            string_to_f64(&self.fee)?,
+            string_to_f64(&self.vol)?,
+            string_to_f64(&self.margin)?,
+            None,
+            self.misc.clone(),
+        )?)
+    }
+}
+
+//A row of a ledgers export, where times are dates rather than timestamps
+#[derive(Deserialize)]
+pub(crate) struct LedgerEntryReportDto {
+    txid: String,
+    refid: String,
+    time: String,
+    #[serde(rename(deserialize = "type"))]
+    entry_type: String,
+    subtype: String,
+    aclass: String,
+    asset: String,
+    amount: String,
+    fee: String,
+    balance: String,
+}
+
+impl LedgerEntryReportDto {
+    pub(crate) fn to_business(&self) -> Result<LedgerEntry, KrakenError> {
+        Ok(ledger_entry::new(
+            self.txid.clone(),
+            self.refid.clone(),
+            report_time_to_f64(&self.time)?,
+            LedgerEntryType::from_value(&self.entry_type)?,
+            self.subtype.clone(),
+            self.aclass.clone(),
+            self.asset.clone(),
+            string_to_f64(&self.amount)?,
+            string_to_f64(&self.fee)?,
+            string_to_f64(&self.balance)?,
+        )?)
+    }
+}
+
+fn string_to_f32(string: &String) -> Result<f32, KrakenError> {
     string
         .parse::<f32>()
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))
+        .map_err(|e| KrakenError::Decoding(e.to_string()))
+}
+
+fn string_to_f64(string: &String) -> Result<f64, KrakenError> {
+    string
+        .parse::<f64>()
+        .map_err(|e| KrakenError::Decoding(e.to_string()))
+}
+
+fn string_to_u64(string: &String) -> Result<u64, KrakenError> {
+    string
+        .parse::<u64>()
+        .map_err(|e| KrakenError::Decoding(e.to_string()))
+}
+
+//Exports write times as UTC dates, with fractional seconds only for trades
+fn report_time_to_f64(time: &str) -> Result<f64, KrakenError> {
+    let time = NaiveDateTime::parse_from_str(time, "%Y-%m-%d %H:%M:%S%.f")
+        .map_err(|e| KrakenError::Decoding(e.to_string()))?;
+    let time = Utc.from_utc_datetime(&time);
+    Ok(time.timestamp() as f64 + time.timestamp_subsec_nanos() as f64 / 1_000_000_000.0)
+}
+
+fn optional_string_to_f32(string: &Option<String>) -> Result<Option<f32>, KrakenError> {
+    match string {
+        Some(string) => Ok(Some(string_to_f32(string)?)),
+        None => Ok(None),
+    }
+}
+
+fn optional_string_to_f64(string: &Option<String>) -> Result<Option<f64>, KrakenError> {
+    match string {
+        Some(string) => Ok(Some(string_to_f64(string)?)),
+        None => Ok(None),
+    }
+}
+
+fn optional_string_to_currency_amount(
+    string: &Option<String>,
+) -> Result<Option<CurrencyAmount>, KrakenError> {
+    match optional_string_to_f32(string)? {
+        Some(amount) => Ok(Some(currency_amount::new(amount)?)),
+        None => Ok(None),
+    }
+}
+
+fn string_to_position(position: &String) -> Result<Position, KrakenError> {
+    let buy_string = String::from("buy");
+    let sell_string = String::from("sell");
+    if position == &buy_string {
+        Ok(Position::Buy)
+    } else if position == &sell_string {
+        Ok(Position::Sell)
+    } else {
+        Err(KrakenError::Decoding(String::from("unknown position")))
+    }
+}
+
+fn string_to_order_type(order_type: &String) -> Result<OrderType, KrakenError> {
+    match order_type.as_str() {
+        "market" => Ok(OrderType::Market),
+        "limit" => Ok(OrderType::Limit),
+        "stop-loss" => Ok(OrderType::StopLoss),
+        "take-profit" => Ok(OrderType::TakeProfit),
+        "stop-loss-limit" => Ok(OrderType::StopLossLimit),
+        "take-profit-limit" => Ok(OrderType::TakeProfitLimit),
+        "trailing-stop" => Ok(OrderType::TrailingStop),
+        "trailing-stop-limit" => Ok(OrderType::TrailingStopLimit),
+        "settle-position" => Ok(OrderType::SettlePosition),
+        _ => Err(KrakenError::Decoding(String::from("unknown order type"))),
+    }
 }
 
 #[derive(Deserialize)]
@@ -182,6 +1457,7 @@ pub(crate) struct OpenOrderDescrDto {
     pair: String,
     #[serde(rename(deserialize = "type"))]
     position: String,
+    #[serde(rename(deserialize = "ordertype"))]
     order_type: String,
     price: String,
     price2: String,
@@ -191,47 +1467,17 @@ pub(crate) struct OpenOrderDescrDto {
 }
 
 impl OpenOrderDescrDto {
-    pub(crate) fn to_business(&self) -> Result<OpenOrderDescription, std::io::Error> {
-        open_order_description::new(
+    pub(crate) fn to_business(&self) -> Result<OpenOrderDescription, KrakenError> {
+        Ok(open_order_description::new(
             self.pair.clone(),
-            self.string_to_position(&self.position)?,
-            self.string_to_order_type(&self.order_type)?,
+            string_to_position(&self.position)?,
+            string_to_order_type(&self.order_type)?,
             string_to_f32(&self.price)?,
             string_to_f32(&self.price2)?,
             self.leverage.clone(),
             self.order.clone(),
             self.close.clone(),
-        )
-    }
-
-    fn string_to_position(&self, position: &String) -> Result<Position, std::io::Error> {
-        let buy_string = String::from("buy");
-        let sell_string = String::from("sell");
-        if position == &buy_string {
-            Ok(Position::Buy)
-        } else if position == &sell_string {
-            Ok(Position::Sell)
-        } else {
-            Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                "unknown position",
-            ))
-        }
-    }
-
-    fn string_to_order_type(&self, order_type: &String) -> Result<OrderType, std::io::Error> {
-        let limit_string = String::from("buy");
-        let market_string = String::from("sell");
-        if order_type == &limit_string {
-            Ok(OrderType::Limit)
-        } else if order_type == &market_string {
-            Ok(OrderType::Market)
-        } else {
-            Err(std::io::Error::new(
-                ErrorKind::InvalidData,
-                "unknown position",
-            ))
-        }
+        )?)
     }
 }
 
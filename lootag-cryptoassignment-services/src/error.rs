@@ -0,0 +1,265 @@
+use std::fmt;
+
+use lootag_cryptoassignment_domain::validation_error::ValidationError;
+
+#[derive(Debug, Clone)]
+pub enum KrakenError {
+    Transport(String),
+    Decoding(String),
+    Signing(String),
+    Validation(ValidationError),
+    Exchange(ExchangeError),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExchangeError {
+    InvalidArguments(String),
+    PermissionDenied,
+    UnknownMethod,
+    TemporaryLockout,
+    InternalError,
+    InvalidKey,
+    InvalidSignature,
+    InvalidNonce,
+    RateLimitExceeded,
+    FeatureDisabled,
+    UnknownAsset,
+    UnknownAssetPair,
+    InsufficientFunds,
+    InsufficientMargin,
+    OrderRateLimitExceeded,
+    OrdersLimitExceeded,
+    PositionsLimitExceeded,
+    UnknownOrder,
+    UnknownPosition,
+    InvalidPrice,
+    OrderMinimumNotMet,
+    ServiceUnavailable,
+    ServiceBusy,
+    CancelOnlyMode,
+    DeadlineElapsed,
+    Other { category: String, message: String },
+}
+
+impl KrakenError {
+    pub(crate) fn is_transient(&self) -> bool {
+        match self {
+            KrakenError::Transport(_) => true,
+            KrakenError::Exchange(error) => error.is_transient(),
+            _ => false,
+        }
+    }
+
+    //Only an invalid nonce or an exceeded rate limit guarantee kraken refused the
+    //request before acting on it. An unavailable or busy service may still have
+    //executed it, so resending could place an order or a withdrawal twice
+    pub(crate) fn is_transient_rejection(&self) -> bool {
+        matches!(
+            self,
+            KrakenError::Exchange(ExchangeError::InvalidNonce)
+                | KrakenError::Exchange(ExchangeError::RateLimitExceeded)
+        )
+    }
+}
+
+impl ExchangeError {
+    //An invalid key is permanent, retrying a wrong or revoked key only delays
+    //the failure until the retries run out
+    fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            ExchangeError::InvalidNonce
+                | ExchangeError::RateLimitExceeded
+                | ExchangeError::ServiceUnavailable
+                | ExchangeError::ServiceBusy
+        )
+    }
+}
+
+pub(crate) fn parse_exchange_error(code: &str) -> ExchangeError {
+    let (category, message) = code.split_once(':').unwrap_or((code, ""));
+    match (category, message) {
+        ("EGeneral", "Permission denied") => ExchangeError::PermissionDenied,
+        ("EGeneral", "Unknown method") => ExchangeError::UnknownMethod,
+        ("EGeneral", "Temporary lockout") => ExchangeError::TemporaryLockout,
+        ("EGeneral", "Internal error") => ExchangeError::InternalError,
+        ("EGeneral", message) if message.starts_with("Invalid arguments") => {
+            ExchangeError::InvalidArguments(
+                message
+                    .trim_start_matches("Invalid arguments")
+                    .trim_start_matches(':')
+                    .to_string(),
+            )
+        }
+        ("EAPI", "Invalid key") => ExchangeError::InvalidKey,
+        ("EAPI", "Invalid signature") => ExchangeError::InvalidSignature,
+        ("EAPI", "Invalid nonce") => ExchangeError::InvalidNonce,
+        ("EAPI", "Rate limit exceeded") => ExchangeError::RateLimitExceeded,
+        ("EAPI", "Feature disabled") => ExchangeError::FeatureDisabled,
+        ("EQuery", "Unknown asset") => ExchangeError::UnknownAsset,
+        ("EQuery", "Unknown asset pair") => ExchangeError::UnknownAssetPair,
+        ("EOrder", "Insufficient funds") => ExchangeError::InsufficientFunds,
+        ("EOrder", "Insufficient margin") => ExchangeError::InsufficientMargin,
+        ("EOrder", "Rate limit exceeded") => ExchangeError::OrderRateLimitExceeded,
+        ("EOrder", "Orders limit exceeded") => ExchangeError::OrdersLimitExceeded,
+        ("EOrder", "Positions limit exceeded") => ExchangeError::PositionsLimitExceeded,
+        ("EOrder", "Unknown order") => ExchangeError::UnknownOrder,
+        ("EOrder", "Unknown position") => ExchangeError::UnknownPosition,
+        ("EOrder", "Invalid price") => ExchangeError::InvalidPrice,
+        ("EOrder", "Order minimum not met") => ExchangeError::OrderMinimumNotMet,
+        ("EService", "Unavailable") => ExchangeError::ServiceUnavailable,
+        ("EService", "Busy") => ExchangeError::ServiceBusy,
+        ("EService", "Market in cancel_only mode") => ExchangeError::CancelOnlyMode,
+        ("EService", "Deadline elapsed") => ExchangeError::DeadlineElapsed,
+        (category, message) => ExchangeError::Other {
+            category: category.to_string(),
+            message: message.to_string(),
+        },
+    }
+}
+
+//Kraken reports warnings in the same array as errors, prefixed with a W
+pub(crate) fn from_error_codes(codes: &[String]) -> Option<KrakenError> {
+    codes
+        .iter()
+        .find(|code| code.starts_with('E'))
+        .map(|code| KrakenError::Exchange(parse_exchange_error(code)))
+}
+
+impl From<ValidationError> for KrakenError {
+    fn from(error: ValidationError) -> Self {
+        KrakenError::Validation(error)
+    }
+}
+
+impl fmt::Display for KrakenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KrakenError::Transport(message) => write!(f, "transport error: {}", message),
+            KrakenError::Decoding(message) => write!(f, "decoding error: {}", message),
+            KrakenError::Signing(message) => write!(f, "signing error: {}", message),
+            KrakenError::Validation(error) => write!(f, "validation error: {}", error),
+            KrakenError::Exchange(error) => write!(f, "exchange error: {:?}", error),
+        }
+    }
+}
+
+impl std::error::Error for KrakenError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_error_codes, parse_exchange_error, ExchangeError, KrakenError};
+
+    #[test]
+    fn should_parse_exchange_error_return_known_variant() {
+        //Arrange
+        let code = "EOrder:Insufficient funds";
+
+        //Act
+        let error = parse_exchange_error(code);
+
+        //Assert
+        assert_eq!(error, ExchangeError::InsufficientFunds);
+    }
+
+    #[test]
+    fn should_parse_exchange_error_keep_invalid_arguments_detail() {
+        //Arrange
+        let code = "EGeneral:Invalid arguments:volume";
+
+        //Act
+        let error = parse_exchange_error(code);
+
+        //Assert
+        assert_eq!(
+            error,
+            ExchangeError::InvalidArguments(String::from("volume"))
+        );
+    }
+
+    #[test]
+    fn should_parse_exchange_error_return_other_for_unknown_codes() {
+        //Arrange
+        let code = "EFunding:Unknown withdraw key";
+
+        //Act
+        let error = parse_exchange_error(code);
+
+        //Assert
+        assert_eq!(
+            error,
+            ExchangeError::Other {
+                category: String::from("EFunding"),
+                message: String::from("Unknown withdraw key"),
+            }
+        );
+    }
+
+    #[test]
+    fn should_is_transient_rejection_exclude_transport_errors() {
+        //Arrange
+        let error = KrakenError::Transport(String::from("operation timed out"));
+
+        //Act
+        let is_transient_rejection = error.is_transient_rejection();
+
+        //Assert
+        assert!(error.is_transient());
+        assert!(!is_transient_rejection);
+    }
+
+    #[test]
+    fn should_is_transient_rejection_exclude_an_unavailable_service() {
+        //Arrange
+        let error = KrakenError::Exchange(ExchangeError::ServiceUnavailable);
+
+        //Act
+        let is_transient_rejection = error.is_transient_rejection();
+
+        //Assert
+        assert!(error.is_transient());
+        assert!(!is_transient_rejection);
+    }
+
+    #[test]
+    fn should_is_transient_be_false_for_an_invalid_key() {
+        //Arrange
+        let error = KrakenError::Exchange(ExchangeError::InvalidKey);
+
+        //Act
+        let is_transient = error.is_transient();
+
+        //Assert
+        assert!(!is_transient);
+    }
+
+    #[test]
+    fn should_from_error_codes_ignore_warnings() {
+        //Arrange
+        let codes = vec![String::from("WGeneral:Deprecated")];
+
+        //Act
+        let error = from_error_codes(&codes);
+
+        //Assert
+        assert!(error.is_none());
+    }
+
+    #[test]
+    fn should_from_error_codes_return_first_error() {
+        //Arrange
+        let codes = vec![
+            String::from("WGeneral:Deprecated"),
+            String::from("EAPI:Invalid nonce"),
+        ];
+
+        //Act
+        let error = from_error_codes(&codes);
+
+        //Assert
+        assert!(matches!(
+            error,
+            Some(KrakenError::Exchange(ExchangeError::InvalidNonce))
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+use std::io::{Cursor, Read};
+
+use lootag_cryptoassignment_domain::{
+    export::ExportFormat, ledger_entry::LedgerEntry, trade::Trade,
+};
+use serde::de::DeserializeOwned;
+use zip::ZipArchive;
+
+use crate::{
+    dto::{LedgerEntryReportDto, TradeReportDto},
+    error::KrakenError,
+};
+
+pub(crate) fn parse_trades(
+    archive: &[u8],
+    format: ExportFormat,
+) -> Result<Vec<Trade>, KrakenError> {
+    let rows: Vec<TradeReportDto> = read_report(archive, format)?;
+    rows.iter()
+        .map(|row| row.to_business())
+        .collect::<Result<Vec<Trade>, KrakenError>>()
+}
+
+pub(crate) fn parse_ledger_entries(
+    archive: &[u8],
+    format: ExportFormat,
+) -> Result<Vec<LedgerEntry>, KrakenError> {
+    let rows: Vec<LedgerEntryReportDto> = read_report(archive, format)?;
+    rows.iter()
+        .map(|row| row.to_business())
+        .collect::<Result<Vec<LedgerEntry>, KrakenError>>()
+}
+
+//Kraken zips a single report file per export
+fn read_report<T: DeserializeOwned>(
+    archive: &[u8],
+    format: ExportFormat,
+) -> Result<Vec<T>, KrakenError> {
+    let mut archive =
+        ZipArchive::new(Cursor::new(archive)).map_err(|e| KrakenError::Decoding(e.to_string()))?;
+    let mut file = archive
+        .by_index(0)
+        .map_err(|e| KrakenError::Decoding(e.to_string()))?;
+    let mut report = Vec::new();
+    file.read_to_end(&mut report)
+        .map_err(|e| KrakenError::Decoding(e.to_string()))?;
+
+    csv::ReaderBuilder::new()
+        .delimiter(format.delimiter())
+        .from_reader(report.as_slice())
+        .deserialize()
+        .collect::<Result<Vec<T>, csv::Error>>()
+        .map_err(|e| KrakenError::Decoding(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use lootag_cryptoassignment_domain::{
+        export::ExportFormat, ledger_entry::LedgerEntryType, open_order_description::Position,
+    };
+    use zip::{write::FileOptions, ZipWriter};
+
+    use super::{parse_ledger_entries, parse_trades};
+
+    #[test]
+    fn should_parse_trades_read_every_row_of_a_zipped_csv() {
+        //Arrange
+        let report = "\"txid\",\"ordertxid\",\"pair\",\"time\",\"type\",\"ordertype\",\"price\",\"cost\",\"fee\",\"vol\",\"margin\",\"misc\",\"ledgers\"\n\
+            \"TDLH43-DVQXD-2KHVYY\",\"OQCLML-BW3P3-BUCMWZ\",\"XXBTZUSD\",\"2023-07-06 18:14:19.8542\",\"buy\",\"limit\",\"30000.0\",\"300.0\",\"0.78\",\"0.01\",\"0.0\",\"\",\"L4UESK-KG3EQ-UFO4T5\"\n\
+            \"TDLH43-DVQXD-2KHVYZ\",\"OQCLML-BW3P3-BUCMXA\",\"XXBTZUSD\",\"2023-07-07 09:01:02\",\"sell\",\"market\",\"31000.0\",\"310.0\",\"0.81\",\"0.01\",\"0.0\",\"\",\"L4UESK-KG3EQ-UFO4T6\"\n";
+        let archive = zip("trades.csv", report);
+
+        //Act
+        let trades = parse_trades(&archive, ExportFormat::Csv).unwrap();
+
+        //Assert
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].identifier(), &String::from("TDLH43-DVQXD-2KHVYY"));
+        assert!((trades[0].time() - 1688667259.8542).abs() < 0.001);
+        assert_eq!(trades[1].position(), Position::Sell);
+        assert_eq!(trades[0].is_maker(), None);
+    }
+
+    #[test]
+    fn should_parse_trades_keep_every_decimal_of_the_amounts() {
+        //Arrange
+        let report = "\"txid\",\"ordertxid\",\"pair\",\"time\",\"type\",\"ordertype\",\"price\",\"cost\",\"fee\",\"vol\",\"margin\",\"misc\",\"ledgers\"\n\
+            \"TDLH43-DVQXD-2KHVYY\",\"OQCLML-BW3P3-BUCMWZ\",\"XXBTZUSD\",\"2023-07-06 18:14:19\",\"buy\",\"limit\",\"30000.1\",\"35376.53400111\",\"91.97898848\",\"1.17921191\",\"0.0\",\"\",\"L4UESK-KG3EQ-UFO4T5\"\n";
+        let archive = zip("trades.csv", report);
+
+        //Act
+        let trades = parse_trades(&archive, ExportFormat::Csv).unwrap();
+
+        //Assert
+        assert_eq!(format!("{:.8}", trades[0].volume()), "1.17921191");
+        assert_eq!(format!("{:.8}", trades[0].cost()), "35376.53400111");
+        assert_eq!(format!("{:.8}", trades[0].fee()), "91.97898848");
+    }
+
+    #[test]
+    fn should_parse_ledger_entries_read_a_zipped_tsv() {
+        //Arrange
+        let report = "txid\trefid\ttime\ttype\tsubtype\taclass\tasset\twallet\tamount\tfee\tbalance\n\
+            L4UESK-KG3EQ-UFO4T5\tTDLH43-DVQXD-2KHVYY\t2023-07-06 18:14:19\ttrade\t\tcurrency\tZUSD\tspot / main\t-300.0\t0.78\t699.22\n";
+        let archive = zip("ledgers.tsv", report);
+
+        //Act
+        let entries = parse_ledger_entries(&archive, ExportFormat::Tsv).unwrap();
+
+        //Assert
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].entry_type(), &LedgerEntryType::Trade);
+        assert_eq!(entries[0].amount(), -300.0);
+    }
+
+    #[test]
+    fn should_parse_ledger_entries_keep_every_decimal_of_the_balance() {
+        //Arrange
+        let report = "txid\trefid\ttime\ttype\tsubtype\taclass\tasset\twallet\tamount\tfee\tbalance\n\
+            L4UESK-KG3EQ-UFO4T5\tTDLH43-DVQXD-2KHVYY\t2023-07-06 18:14:19\ttrade\t\tcurrency\tXXBT\tspot / main\t1.17921191\t0.00000001\t12.34567891\n";
+        let archive = zip("ledgers.tsv", report);
+
+        //Act
+        let entries = parse_ledger_entries(&archive, ExportFormat::Tsv).unwrap();
+
+        //Assert
+        assert_eq!(format!("{:.8}", entries[0].amount()), "1.17921191");
+        assert_eq!(format!("{:.8}", entries[0].fee()), "0.00000001");
+        assert_eq!(format!("{:.8}", entries[0].balance()), "12.34567891");
+    }
+
+    #[test]
+    fn should_parse_ledger_entries_keep_rows_of_unlisted_types() {
+        //Arrange
+        let report = "txid\trefid\ttime\ttype\tsubtype\taclass\tasset\twallet\tamount\tfee\tbalance\n\
+            L4UESK-KG3EQ-UFO4T7\tSTHFSYV-COKEV-2N3FK7\t2023-07-08 10:00:00\tearn\tallocation\tcurrency\tDOT\tspot / main\t-10.0\t0.0\t5.0\n\
+            L4UESK-KG3EQ-UFO4T8\tBOKSIGS-FEDLI-2HNOUM\t2023-07-09 10:00:00\tbonus\t\tcurrency\tZUSD\tspot / main\t10.0\t0.0\t709.22\n";
+        let archive = zip("ledgers.tsv", report);
+
+        //Act
+        let entries = parse_ledger_entries(&archive, ExportFormat::Tsv).unwrap();
+
+        //Assert
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].entry_type(), &LedgerEntryType::Earn);
+        assert_eq!(
+            entries[1].entry_type(),
+            &LedgerEntryType::Other(String::from("bonus"))
+        );
+    }
+
+    fn zip(name: &str, report: &str) -> Vec<u8> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        writer.start_file(name, FileOptions::default()).unwrap();
+        writer.write_all(report.as_bytes()).unwrap();
+        writer.finish().unwrap().into_inner()
+    }
+}
@@ -0,0 +1,132 @@
+use lootag_cryptoassignment_domain::ledger_entry::LedgerEntryType;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CloseTime {
+    Open,
+    Close,
+    Both,
+}
+
+impl CloseTime {
+    pub fn value(&self) -> &'static str {
+        match self {
+            CloseTime::Open => "open",
+            CloseTime::Close => "close",
+            CloseTime::Both => "both",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ClosedOrdersFilter {
+    pub(crate) start: Option<u64>,
+    pub(crate) end: Option<u64>,
+    pub(crate) closetime: CloseTime,
+    pub(crate) userref: Option<u32>,
+    pub(crate) trades: bool,
+}
+
+impl ClosedOrdersFilter {
+    pub fn new(
+        start: Option<u64>,
+        end: Option<u64>,
+        closetime: CloseTime,
+        userref: Option<u32>,
+        trades: bool,
+    ) -> Self {
+        Self {
+            start: start,
+            end: end,
+            closetime: closetime,
+            userref: userref,
+            trades: trades,
+        }
+    }
+}
+
+impl Default for ClosedOrdersFilter {
+    fn default() -> Self {
+        Self::new(None, None, CloseTime::Both, None, false)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TradeType {
+    All,
+    AnyPosition,
+    ClosedPosition,
+    ClosingPosition,
+    NoPosition,
+}
+
+impl TradeType {
+    pub fn value(&self) -> &'static str {
+        match self {
+            TradeType::All => "all",
+            TradeType::AnyPosition => "any position",
+            TradeType::ClosedPosition => "closed position",
+            TradeType::ClosingPosition => "closing position",
+            TradeType::NoPosition => "no position",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TradesHistoryFilter {
+    pub(crate) trade_type: TradeType,
+    pub(crate) start: Option<u64>,
+    pub(crate) end: Option<u64>,
+    pub(crate) consolidate_taker: bool,
+}
+
+impl TradesHistoryFilter {
+    pub fn new(
+        trade_type: TradeType,
+        start: Option<u64>,
+        end: Option<u64>,
+        consolidate_taker: bool,
+    ) -> Self {
+        Self {
+            trade_type: trade_type,
+            start: start,
+            end: end,
+            consolidate_taker: consolidate_taker,
+        }
+    }
+}
+
+impl Default for TradesHistoryFilter {
+    fn default() -> Self {
+        Self::new(TradeType::All, None, None, true)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LedgersFilter {
+    pub(crate) assets: Vec<String>,
+    pub(crate) entry_type: Option<LedgerEntryType>,
+    pub(crate) start: Option<u64>,
+    pub(crate) end: Option<u64>,
+}
+
+impl LedgersFilter {
+    pub fn new(
+        assets: Vec<String>,
+        entry_type: Option<LedgerEntryType>,
+        start: Option<u64>,
+        end: Option<u64>,
+    ) -> Self {
+        Self {
+            assets: assets,
+            entry_type: entry_type,
+            start: start,
+            end: end,
+        }
+    }
+}
+
+impl Default for LedgersFilter {
+    fn default() -> Self {
+        Self::new(vec![], None, None, None)
+    }
+}
@@ -1,187 +1,1815 @@
+mod dead_man_switch;
 mod dto;
+mod error;
+mod export;
+mod filter;
 mod nonce;
+mod pagination;
 mod payload;
 mod uri;
 mod web_request;
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
-use std::{io::ErrorKind, time::Duration};
+use std::time::Duration;
 
 use backoff::future::retry;
 
 use backoff::ExponentialBackoff;
 
-use dto::{ServerTimeDto, XbtUsdPairDto};
+use chrono::{DateTime, Utc};
+use dto::{
+    AssetPairDto, OhlcDto, OrderBookDto, PublicTradesDto, ResponseDto, ServerTimeDto, SpreadsDto,
+    TickerDto,
+};
+use futures::Stream;
 use lootag_cryptoassignment_domain::{
-    credentials::Credentials, open_order::OpenOrder, server_time::ServerTime, xbt_usd::XbtUsd,
+    asset_pair::{AssetPair, AssetPairInfo},
+    balance::Balances,
+    candle_series::{CandleSeries, Interval},
+    credentials::Credentials,
+    dead_man_switch::DeadManSwitch,
+    deposit::{Deposit, DepositAddress, DepositMethod},
+    earn::{EarnAllocations, EarnRequest, EarnStrategy, LockType},
+    export::{Export, ExportRequest, RemovalType, ReportType},
+    ledger_entry::LedgerEntry,
+    new_order::{NewOrder, NewOrderBuilder},
+    open_order::OpenOrder,
+    order_batch,
+    order_book::OrderBook,
+    order_cancellation::{self, OrderCancellation},
+    order_confirmation::OrderConfirmation,
+    order_edit::{OrderEdit, OrderEditConfirmation, OrderReference},
+    position::Position as MarginPosition,
+    public_trade::{PublicTrade, PublicTradeSeries},
+    server_time::ServerTime,
+    spread::{Spread, SpreadSeries},
+    subaccount::Subaccount,
+    ticker::Ticker,
+    trade::Trade,
+    trade_balance::TradeBalance,
+    trade_volume::TradeVolume,
+    transfer::{AccountTransfer, AccountTransferConfirmation, WalletTransfer},
+    validation_error,
+    withdrawal::{Withdrawal, WithdrawalInfo, WithdrawalRequest},
 };
 use reqwest::header::CONTENT_TYPE;
+use serde::de::DeserializeOwned;
 use web_request::encoded_payload;
 
-use crate::dto::OpenOrdersDto;
-use crate::{
-    payload::{OpenOrdersRequestPayload, RequestPayload},
-    web_request::{api_sign, WebRequest},
-};
+use crate::dto::{
+    AccountTransferDto, AddExportDto, AddOrderBatchDto, AddOrderDto, AmendOrderDto, BalancesDto,
+    CancelAllOrdersAfterDto, CancelOrderDto, ClosedOrdersDto, DepositAddressDto, DepositDto,
+    DepositMethodDto, EarnAllocationsDto, EarnStatusDto, EarnStrategiesDto, EditOrderDto,
+    ExportDto, ExtendedBalancesDto, LedgerEntryDto, LedgersDto, OpenOrderDto, OpenOrdersDto,
+    OpenPositionDto, RemoveExportDto, TradeBalanceDto, TradeDto, TradeVolumeDto, TradesHistoryDto,
+    WalletTransferDto, WithdrawDto, WithdrawInfoDto, WithdrawalDto,
+};
+use crate::{
+    payload::{
+        AccountTransferRequestPayload, AddExportRequestPayload, AddOrderBatchRequestPayload,
+        AddOrderRequestPayload, AmendOrderRequestPayload, BalanceRequestPayload,
+        CancelAllOrdersAfterRequestPayload, CancelAllRequestPayload,
+        CancelOrderBatchRequestPayload, CancelOrderRequestPayload, ClosedOrdersRequestPayload,
+        CreateSubaccountRequestPayload, DepositAddressesRequestPayload,
+        DepositMethodsRequestPayload, DepositStatusRequestPayload, EarnAllocateRequestPayload,
+        EarnAllocateStatusRequestPayload, EarnAllocationsRequestPayload,
+        EarnDeallocateRequestPayload, EarnDeallocateStatusRequestPayload,
+        EarnStrategiesRequestPayload, EditOrderRequestPayload, ExportStatusRequestPayload,
+        ExtendedBalanceRequestPayload, LedgersRequestPayload, OpenOrdersRequestPayload,
+        OpenPositionsRequestPayload, QueryLedgersRequestPayload, QueryOrdersRequestPayload,
+        QueryTradesRequestPayload, RemoveExportRequestPayload, RequestPayload,
+        RetrieveExportRequestPayload, TradeBalanceRequestPayload, TradeVolumeRequestPayload,
+        TradesHistoryRequestPayload, WalletTransferRequestPayload, WithdrawCancelRequestPayload,
+        WithdrawInfoRequestPayload, WithdrawRequestPayload, WithdrawStatusRequestPayload,
+    },
+    web_request::api_sign,
+};
+
+pub use crate::dead_man_switch::DeadManSwitchKeeper;
+pub use crate::error::{ExchangeError, KrakenError};
+pub use crate::filter::{
+    CloseTime, ClosedOrdersFilter, LedgersFilter, TradeType, TradesHistoryFilter,
+};
+
+#[derive(Debug)]
+pub struct KrakenService {
+    configuration: Configuration,
+}
+
+impl KrakenService {
+    pub fn new(configuration: Configuration) -> Self {
+        KrakenService {
+            configuration: configuration,
+        }
+    }
+}
+
+impl KrakenService {
+    pub async fn retrieve_server_time(&self) -> Result<ServerTime, KrakenError> {
+        retrieve_server_time_impl(&self.configuration).await
+    }
+
+    pub async fn retrieve_asset_pairs(
+        &self,
+        pairs: &[String],
+        info: AssetPairInfo,
+    ) -> Result<HashMap<String, AssetPair>, KrakenError> {
+        retrieve_asset_pairs_impl(&self.configuration, Some(pairs), info).await
+    }
+
+    pub async fn retrieve_all_asset_pairs(
+        &self,
+        info: AssetPairInfo,
+    ) -> Result<HashMap<String, AssetPair>, KrakenError> {
+        retrieve_asset_pairs_impl(&self.configuration, None, info).await
+    }
+
+    pub async fn retrieve_ticker(
+        &self,
+        pairs: &[String],
+    ) -> Result<HashMap<String, Ticker>, KrakenError> {
+        retrieve_ticker_impl(&self.configuration, pairs).await
+    }
+
+    pub async fn retrieve_ohlc(
+        &self,
+        pair: &String,
+        interval: Interval,
+        since: Option<u64>,
+    ) -> Result<CandleSeries, KrakenError> {
+        retrieve_ohlc_impl(&self.configuration, pair, interval, since).await
+    }
+
+    pub async fn retrieve_order_book(
+        &self,
+        pair: &String,
+        count: Option<u32>,
+    ) -> Result<OrderBook, KrakenError> {
+        retrieve_order_book_impl(&self.configuration, pair, count).await
+    }
+
+    pub async fn retrieve_trades(
+        &self,
+        pair: &String,
+        since: Option<u64>,
+        count: Option<u32>,
+    ) -> Result<PublicTradeSeries, KrakenError> {
+        retrieve_trades_impl(&self.configuration, pair, since, count).await
+    }
+
+    pub async fn retrieve_spreads(
+        &self,
+        pair: &String,
+        since: Option<u64>,
+    ) -> Result<SpreadSeries, KrakenError> {
+        retrieve_spreads_impl(&self.configuration, pair, since).await
+    }
+
+    pub fn stream_trades<'a>(
+        &'a self,
+        pair: &'a String,
+        since: Option<u64>,
+    ) -> impl Stream<Item = Result<PublicTrade, KrakenError>> + 'a {
+        pagination::follow_cursor(since, move |since| async move {
+            let series = retrieve_trades_impl(&self.configuration, pair, since, None).await?;
+            let last = series.last();
+            Ok((series.into_trades(), last))
+        })
+    }
+
+    pub fn stream_spreads<'a>(
+        &'a self,
+        pair: &'a String,
+        since: Option<u64>,
+    ) -> impl Stream<Item = Result<Spread, KrakenError>> + 'a {
+        pagination::follow_cursor(since, move |since| async move {
+            let series = retrieve_spreads_impl(&self.configuration, pair, since).await?;
+            let last = series.last();
+            Ok((series.into_spreads(), last))
+        })
+    }
+
+    pub async fn retrieve_open_orders(
+        &self,
+        credentials: &Credentials,
+    ) -> Result<Vec<OpenOrder>, KrakenError> {
+        retrieve_open_orders_impl(&self.configuration, credentials).await
+    }
+
+    pub async fn retrieve_open_positions(
+        &self,
+        credentials: &Credentials,
+        txids: &[String],
+        docalcs: bool,
+    ) -> Result<Vec<MarginPosition>, KrakenError> {
+        retrieve_open_positions_impl(&self.configuration, credentials, txids, docalcs).await
+    }
+
+    pub async fn retrieve_consolidated_positions(
+        &self,
+        credentials: &Credentials,
+        docalcs: bool,
+    ) -> Result<Vec<MarginPosition>, KrakenError> {
+        retrieve_consolidated_positions_impl(&self.configuration, credentials, docalcs).await
+    }
+
+    pub async fn retrieve_trade_volume(
+        &self,
+        credentials: &Credentials,
+        pairs: &[String],
+    ) -> Result<TradeVolume, KrakenError> {
+        retrieve_trade_volume_impl(&self.configuration, credentials, pairs).await
+    }
+
+    pub async fn retrieve_closed_orders(
+        &self,
+        credentials: &Credentials,
+        filter: &ClosedOrdersFilter,
+        ofs: Option<u32>,
+    ) -> Result<Vec<OpenOrder>, KrakenError> {
+        let (orders, _count) =
+            retrieve_closed_orders_impl(&self.configuration, credentials, filter, ofs).await?;
+        Ok(orders)
+    }
+
+    pub fn stream_closed_orders<'a>(
+        &'a self,
+        credentials: &'a Credentials,
+        filter: &'a ClosedOrdersFilter,
+    ) -> impl Stream<Item = Result<OpenOrder, KrakenError>> + 'a {
+        pagination::follow_offset(move |ofs| {
+            retrieve_closed_orders_impl(&self.configuration, credentials, filter, Some(ofs))
+        })
+    }
+
+    pub async fn query_orders(
+        &self,
+        credentials: &Credentials,
+        txids: &[String],
+        include_trades: bool,
+        userref: Option<u32>,
+    ) -> Result<Vec<OpenOrder>, KrakenError> {
+        query_orders_impl(
+            &self.configuration,
+            credentials,
+            txids,
+            include_trades,
+            userref,
+        )
+        .await
+    }
+
+    pub async fn retrieve_trades_history(
+        &self,
+        credentials: &Credentials,
+        filter: &TradesHistoryFilter,
+        ofs: Option<u32>,
+    ) -> Result<Vec<Trade>, KrakenError> {
+        let (trades, _count) =
+            retrieve_trades_history_impl(&self.configuration, credentials, filter, ofs).await?;
+        Ok(trades)
+    }
+
+    pub fn stream_trades_history<'a>(
+        &'a self,
+        credentials: &'a Credentials,
+        filter: &'a TradesHistoryFilter,
+    ) -> impl Stream<Item = Result<Trade, KrakenError>> + 'a {
+        pagination::follow_offset(move |ofs| {
+            retrieve_trades_history_impl(&self.configuration, credentials, filter, Some(ofs))
+        })
+    }
+
+    pub async fn query_trades(
+        &self,
+        credentials: &Credentials,
+        txids: &[String],
+    ) -> Result<Vec<Trade>, KrakenError> {
+        query_trades_impl(&self.configuration, credentials, txids).await
+    }
+
+    pub async fn resolve_order_trades(
+        &self,
+        credentials: &Credentials,
+        order: &OpenOrder,
+    ) -> Result<Vec<Trade>, KrakenError> {
+        query_trades_impl(&self.configuration, credentials, order.trades()).await
+    }
+
+    pub async fn retrieve_ledgers(
+        &self,
+        credentials: &Credentials,
+        filter: &LedgersFilter,
+        ofs: Option<u32>,
+    ) -> Result<Vec<LedgerEntry>, KrakenError> {
+        let (entries, _count) =
+            retrieve_ledgers_impl(&self.configuration, credentials, filter, ofs).await?;
+        Ok(entries)
+    }
+
+    pub fn stream_ledgers<'a>(
+        &'a self,
+        credentials: &'a Credentials,
+        filter: &'a LedgersFilter,
+    ) -> impl Stream<Item = Result<LedgerEntry, KrakenError>> + 'a {
+        pagination::follow_offset(move |ofs| {
+            retrieve_ledgers_impl(&self.configuration, credentials, filter, Some(ofs))
+        })
+    }
+
+    pub async fn query_ledgers(
+        &self,
+        credentials: &Credentials,
+        ids: &[String],
+    ) -> Result<Vec<LedgerEntry>, KrakenError> {
+        query_ledgers_impl(&self.configuration, credentials, ids).await
+    }
+
+    pub async fn add_order(
+        &self,
+        credentials: &Credentials,
+        order: &NewOrder,
+        validate: bool,
+    ) -> Result<OrderConfirmation, KrakenError> {
+        add_order_impl(&self.configuration, credentials, order, validate).await
+    }
+
+    pub async fn add_order_batch(
+        &self,
+        credentials: &Credentials,
+        pair: &AssetPair,
+        orders: Vec<NewOrderBuilder>,
+        deadline: Option<DateTime<Utc>>,
+        validate: bool,
+    ) -> Result<Vec<Result<OrderConfirmation, KrakenError>>, KrakenError> {
+        add_order_batch_impl(
+            &self.configuration,
+            credentials,
+            pair,
+            orders,
+            deadline,
+            validate,
+        )
+        .await
+    }
+
+    pub async fn edit_order(
+        &self,
+        credentials: &Credentials,
+        edit: &OrderEdit,
+        validate: bool,
+    ) -> Result<OrderEditConfirmation, KrakenError> {
+        edit_order_impl(&self.configuration, credentials, edit, validate).await
+    }
+
+    pub async fn amend_order(
+        &self,
+        credentials: &Credentials,
+        edit: &OrderEdit,
+    ) -> Result<OrderEditConfirmation, KrakenError> {
+        amend_order_impl(&self.configuration, credentials, edit).await
+    }
+
+    pub async fn cancel_order(
+        &self,
+        credentials: &Credentials,
+        reference: &OrderReference,
+    ) -> Result<OrderCancellation, KrakenError> {
+        cancel_order_impl(&self.configuration, credentials, reference).await
+    }
+
+    pub async fn cancel_order_batch(
+        &self,
+        credentials: &Credentials,
+        references: &[OrderReference],
+    ) -> Result<OrderCancellation, KrakenError> {
+        cancel_order_batch_impl(&self.configuration, credentials, references).await
+    }
+
+    pub async fn cancel_all(
+        &self,
+        credentials: &Credentials,
+    ) -> Result<OrderCancellation, KrakenError> {
+        cancel_all_impl(&self.configuration, credentials).await
+    }
+
+    pub async fn cancel_all_orders_after(
+        &self,
+        credentials: &Credentials,
+        timeout: Duration,
+    ) -> Result<DeadManSwitch, KrakenError> {
+        cancel_all_orders_after_impl(&self.configuration, credentials, timeout).await
+    }
+
+    //Needs to be called from within a tokio runtime, the keeper re-arms the
+    //timer every interval until it's stopped or dropped
+    pub fn keep_dead_man_switch(
+        &self,
+        credentials: &Credentials,
+        interval: Duration,
+        timeout: Duration,
+    ) -> Result<DeadManSwitchKeeper, KrakenError> {
+        let _ = self
+            .configuration
+            .ensure_executable("orders", "scheduled for cancellation")?;
+        if interval >= timeout {
+            return Err(KrakenError::Validation(validation_error::new(
+                "the timer needs to be re-armed before it runs out",
+            )));
+        }
+        Ok(dead_man_switch::spawn(
+            self.configuration.clone(),
+            credentials.clone(),
+            interval,
+            timeout,
+        ))
+    }
+
+    pub async fn retrieve_deposit_methods(
+        &self,
+        credentials: &Credentials,
+        asset: &String,
+    ) -> Result<Vec<DepositMethod>, KrakenError> {
+        retrieve_deposit_methods_impl(&self.configuration, credentials, asset).await
+    }
+
+    pub async fn retrieve_deposit_addresses(
+        &self,
+        credentials: &Credentials,
+        asset: &String,
+        method: &String,
+        new: bool,
+    ) -> Result<Vec<DepositAddress>, KrakenError> {
+        retrieve_deposit_addresses_impl(&self.configuration, credentials, asset, method, new).await
+    }
+
+    pub async fn retrieve_deposit_status(
+        &self,
+        credentials: &Credentials,
+        asset: Option<&String>,
+        method: Option<&String>,
+    ) -> Result<Vec<Deposit>, KrakenError> {
+        retrieve_deposit_status_impl(&self.configuration, credentials, asset, method).await
+    }
+
+    pub async fn retrieve_withdrawal_info(
+        &self,
+        credentials: &Credentials,
+        asset: &String,
+        key: &String,
+        amount: f64,
+    ) -> Result<WithdrawalInfo, KrakenError> {
+        retrieve_withdrawal_info_impl(&self.configuration, credentials, asset, key, amount).await
+    }
+
+    //Returns the reference id kraken assigned to the withdrawal
+    pub async fn withdraw(
+        &self,
+        credentials: &Credentials,
+        request: &WithdrawalRequest,
+    ) -> Result<String, KrakenError> {
+        withdraw_impl(&self.configuration, credentials, request).await
+    }
+
+    pub async fn retrieve_withdrawal_status(
+        &self,
+        credentials: &Credentials,
+        asset: Option<&String>,
+        method: Option<&String>,
+    ) -> Result<Vec<Withdrawal>, KrakenError> {
+        retrieve_withdrawal_status_impl(&self.configuration, credentials, asset, method).await
+    }
+
+    pub async fn cancel_withdrawal(
+        &self,
+        credentials: &Credentials,
+        asset: &String,
+        refid: &String,
+    ) -> Result<bool, KrakenError> {
+        cancel_withdrawal_impl(&self.configuration, credentials, asset, refid).await
+    }
+
+    //Returns the reference id kraken assigned to the transfer
+    pub async fn wallet_transfer(
+        &self,
+        credentials: &Credentials,
+        transfer: &WalletTransfer,
+    ) -> Result<String, KrakenError> {
+        wallet_transfer_impl(&self.configuration, credentials, transfer).await
+    }
+
+    pub async fn account_transfer(
+        &self,
+        credentials: &Credentials,
+        transfer: &AccountTransfer,
+    ) -> Result<AccountTransferConfirmation, KrakenError> {
+        account_transfer_impl(&self.configuration, credentials, transfer).await
+    }
+
+    pub async fn create_subaccount(
+        &self,
+        credentials: &Credentials,
+        subaccount: &Subaccount,
+    ) -> Result<bool, KrakenError> {
+        create_subaccount_impl(&self.configuration, credentials, subaccount).await
+    }
+
+    //An empty set of lock types returns the strategies of every lock type
+    pub async fn retrieve_earn_strategies(
+        &self,
+        credentials: &Credentials,
+        asset: Option<&String>,
+        lock_types: &[LockType],
+    ) -> Result<Vec<EarnStrategy>, KrakenError> {
+        retrieve_earn_strategies_impl(&self.configuration, credentials, asset, lock_types).await
+    }
+
+    pub async fn retrieve_earn_allocations(
+        &self,
+        credentials: &Credentials,
+        converted_asset: Option<&String>,
+        hide_zero_allocations: bool,
+    ) -> Result<EarnAllocations, KrakenError> {
+        retrieve_earn_allocations_impl(
+            &self.configuration,
+            credentials,
+            converted_asset,
+            hide_zero_allocations,
+        )
+        .await
+    }
+
+    //Allocations are processed asynchronously, poll retrieve_earn_allocate_status
+    //to know when it's done
+    pub async fn allocate_earn(
+        &self,
+        credentials: &Credentials,
+        request: &EarnRequest,
+    ) -> Result<bool, KrakenError> {
+        allocate_earn_impl(&self.configuration, credentials, request).await
+    }
+
+    pub async fn deallocate_earn(
+        &self,
+        credentials: &Credentials,
+        request: &EarnRequest,
+    ) -> Result<bool, KrakenError> {
+        deallocate_earn_impl(&self.configuration, credentials, request).await
+    }
+
+    //True while an allocation to the strategy is still pending
+    pub async fn retrieve_earn_allocate_status(
+        &self,
+        credentials: &Credentials,
+        strategy_id: &String,
+    ) -> Result<bool, KrakenError> {
+        retrieve_earn_allocate_status_impl(&self.configuration, credentials, strategy_id).await
+    }
+
+    //True while a deallocation from the strategy is still pending
+    pub async fn retrieve_earn_deallocate_status(
+        &self,
+        credentials: &Credentials,
+        strategy_id: &String,
+    ) -> Result<bool, KrakenError> {
+        retrieve_earn_deallocate_status_impl(&self.configuration, credentials, strategy_id).await
+    }
+
+    //Returns the id of the export, which kraken builds in the background
+    pub async fn add_export(
+        &self,
+        credentials: &Credentials,
+        request: &ExportRequest,
+    ) -> Result<String, KrakenError> {
+        add_export_impl(&self.configuration, credentials, request).await
+    }
+
+    pub async fn retrieve_export_status(
+        &self,
+        credentials: &Credentials,
+        report: ReportType,
+    ) -> Result<Vec<Export>, KrakenError> {
+        retrieve_export_status_impl(&self.configuration, credentials, report).await
+    }
+
+    pub async fn retrieve_trades_export(
+        &self,
+        credentials: &Credentials,
+        export: &Export,
+    ) -> Result<Vec<Trade>, KrakenError> {
+        let archive =
+            retrieve_export_impl(&self.configuration, credentials, export, ReportType::Trades)
+                .await?;
+        export::parse_trades(&archive, export.format())
+    }
+
+    pub async fn retrieve_ledgers_export(
+        &self,
+        credentials: &Credentials,
+        export: &Export,
+    ) -> Result<Vec<LedgerEntry>, KrakenError> {
+        let archive = retrieve_export_impl(
+            &self.configuration,
+            credentials,
+            export,
+            ReportType::Ledgers,
+        )
+        .await?;
+        export::parse_ledger_entries(&archive, export.format())
+    }
+
+    pub async fn remove_export(
+        &self,
+        credentials: &Credentials,
+        id: &String,
+        removal: RemovalType,
+    ) -> Result<bool, KrakenError> {
+        remove_export_impl(&self.configuration, credentials, id, removal).await
+    }
+
+    pub async fn retrieve_balance(
+        &self,
+        credentials: &Credentials,
+    ) -> Result<Balances, KrakenError> {
+        retrieve_balance_impl(&self.configuration, credentials).await
+    }
+
+    pub async fn retrieve_extended_balance(
+        &self,
+        credentials: &Credentials,
+    ) -> Result<Balances, KrakenError> {
+        retrieve_extended_balance_impl(&self.configuration, credentials).await
+    }
+
+    pub async fn retrieve_trade_balance(
+        &self,
+        credentials: &Credentials,
+        asset: Option<&String>,
+    ) -> Result<TradeBalance, KrakenError> {
+        retrieve_trade_balance_impl(&self.configuration, credentials, asset).await
+    }
+}
+
+async fn retrieve_server_time_impl(
+    configuration: &Configuration,
+) -> Result<ServerTime, KrakenError> {
+    let dto: ServerTimeDto = request_public(configuration, "/public/Time", &[]).await?;
+
+    dto.to_business()
+}
+
+async fn retrieve_asset_pairs_impl(
+    configuration: &Configuration,
+    pairs: Option<&[String]>,
+    info: AssetPairInfo,
+) -> Result<HashMap<String, AssetPair>, KrakenError> {
+    let mut query = vec![("info", String::from(info.value()))];
+    if let Some(pairs) = pairs {
+        query.push(("pair", pairs.join(",")));
+    }
+    let dto: HashMap<String, AssetPairDto> =
+        request_public(configuration, "/public/AssetPairs", &query).await?;
+
+    dto.iter()
+        .map(|(name, pair)| Ok((name.clone(), pair.to_business(name)?)))
+        .collect::<Result<HashMap<String, AssetPair>, KrakenError>>()
+}
+
+async fn retrieve_ticker_impl(
+    configuration: &Configuration,
+    pairs: &[String],
+) -> Result<HashMap<String, Ticker>, KrakenError> {
+    let query = [("pair", pairs.join(","))];
+    let dto: HashMap<String, TickerDto> =
+        request_public(configuration, "/public/Ticker", &query).await?;
+
+    dto.iter()
+        .map(|(pair, ticker)| Ok((pair.clone(), ticker.to_business(pair)?)))
+        .collect::<Result<HashMap<String, Ticker>, KrakenError>>()
+}
+
+async fn retrieve_ohlc_impl(
+    configuration: &Configuration,
+    pair: &String,
+    interval: Interval,
+    since: Option<u64>,
+) -> Result<CandleSeries, KrakenError> {
+    let mut query = vec![
+        ("pair", pair.clone()),
+        ("interval", interval.minutes().to_string()),
+    ];
+    if let Some(since) = since {
+        query.push(("since", since.to_string()));
+    }
+    let dto: OhlcDto = request_public(configuration, "/public/OHLC", &query).await?;
+
+    dto.to_business(interval)
+}
+
+async fn retrieve_order_book_impl(
+    configuration: &Configuration,
+    pair: &String,
+    count: Option<u32>,
+) -> Result<OrderBook, KrakenError> {
+    let mut query = vec![("pair", pair.clone())];
+    if let Some(count) = count {
+        query.push(("count", count.to_string()));
+    }
+    let dto: HashMap<String, OrderBookDto> =
+        request_public(configuration, "/public/Depth", &query).await?;
+
+    let (pair, order_book) = dto
+        .iter()
+        .next()
+        .ok_or_else(|| KrakenError::Decoding(String::from("missing order book")))?;
+    order_book.to_business(pair)
+}
+
+async fn retrieve_trades_impl(
+    configuration: &Configuration,
+    pair: &String,
+    since: Option<u64>,
+    count: Option<u32>,
+) -> Result<PublicTradeSeries, KrakenError> {
+    let mut query = vec![("pair", pair.clone())];
+    if let Some(since) = since {
+        query.push(("since", since.to_string()));
+    }
+    if let Some(count) = count {
+        query.push(("count", count.to_string()));
+    }
+    let dto: PublicTradesDto = request_public(configuration, "/public/Trades", &query).await?;
+
+    dto.to_business()
+}
+
+async fn retrieve_spreads_impl(
+    configuration: &Configuration,
+    pair: &String,
+    since: Option<u64>,
+) -> Result<SpreadSeries, KrakenError> {
+    let mut query = vec![("pair", pair.clone())];
+    if let Some(since) = since {
+        query.push(("since", since.to_string()));
+    }
+    let dto: SpreadsDto = request_public(configuration, "/public/Spread", &query).await?;
+
+    dto.to_business()
+}
+
+//Public endpoints share the retry policy too, since paging through a feed is the
+//quickest way to run into the public rate limit
+async fn request_public<T: DeserializeOwned>(
+    configuration: &Configuration,
+    path: &str,
+    query: &[(&str, String)],
+) -> Result<T, KrakenError> {
+    let client = reqwest::Client::new();
+    retry(retry_policy(configuration), || async {
+        send_public_request(&client, configuration, path, query)
+            .await
+            .map_err(|e| to_backoff_error(e, KrakenError::is_transient))
+    })
+    .await
+}
+
+async fn send_public_request<T: DeserializeOwned>(
+    client: &reqwest::Client,
+    configuration: &Configuration,
+    path: &str,
+    query: &[(&str, String)],
+) -> Result<T, KrakenError> {
+    let url = format!("{}{}", configuration.base_url, path);
+    let response_json = client
+        .get(&url)
+        .query(query)
+        .send()
+        .await
+        .map_err(|e| KrakenError::Transport(e.to_string()))?
+        .text()
+        .await
+        .map_err(|e| KrakenError::Transport(e.to_string()))?;
+
+    decode(&response_json)
+}
+
+async fn retrieve_open_positions_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    txids: &[String],
+    docalcs: bool,
+) -> Result<Vec<MarginPosition>, KrakenError> {
+    let payload = RequestPayload::OpenPositions(OpenPositionsRequestPayload {
+        txids: txids.to_vec(),
+        docalcs: docalcs,
+        consolidated: false,
+    });
+    let dto: HashMap<String, OpenPositionDto> = request_private(
+        configuration,
+        credentials,
+        "/private/OpenPositions",
+        &payload,
+    )
+    .await?;
+
+    dto.iter()
+        .map(|(identifier, position)| position.to_business(Some(identifier)))
+        .collect::<Result<Vec<MarginPosition>, KrakenError>>()
+}
+
+//Consolidating by market merges every position of a pair into a single one
+async fn retrieve_consolidated_positions_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    docalcs: bool,
+) -> Result<Vec<MarginPosition>, KrakenError> {
+    let payload = RequestPayload::OpenPositions(OpenPositionsRequestPayload {
+        txids: vec![],
+        docalcs: docalcs,
+        consolidated: true,
+    });
+    let dto: Vec<OpenPositionDto> = request_private(
+        configuration,
+        credentials,
+        "/private/OpenPositions",
+        &payload,
+    )
+    .await?;
+
+    dto.iter()
+        .map(|position| position.to_business(None))
+        .collect::<Result<Vec<MarginPosition>, KrakenError>>()
+}
+
+async fn retrieve_open_orders_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+) -> Result<Vec<OpenOrder>, KrakenError> {
+    let payload = RequestPayload::OpenOrders(OpenOrdersRequestPayload {});
+    let dto: OpenOrdersDto =
+        request_private(configuration, credentials, "/private/OpenOrders", &payload).await?;
+
+    dto.open
+        .iter()
+        .map(|(identifier, order)| order.to_business(identifier))
+        .collect::<Result<Vec<OpenOrder>, KrakenError>>()
+}
+
+//The closed orders come back as a map, so each page is sorted with the most
+//recently closed orders first, the same order kraken pages them in
+async fn retrieve_closed_orders_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    filter: &ClosedOrdersFilter,
+    ofs: Option<u32>,
+) -> Result<(Vec<OpenOrder>, u32), KrakenError> {
+    let payload = RequestPayload::ClosedOrders(ClosedOrdersRequestPayload {
+        filter: filter.clone(),
+        ofs: ofs,
+    });
+    let dto: ClosedOrdersDto = request_private(
+        configuration,
+        credentials,
+        "/private/ClosedOrders",
+        &payload,
+    )
+    .await?;
+
+    let mut orders = dto
+        .closed
+        .iter()
+        .map(|(identifier, order)| order.to_business(identifier))
+        .collect::<Result<Vec<OpenOrder>, KrakenError>>()?;
+    orders.sort_by(|a, b| {
+        b.closetm()
+            .partial_cmp(&a.closetm())
+            .unwrap_or(Ordering::Equal)
+    });
+    Ok((orders, dto.count))
+}
+
+//Kraken accepts at most 50 transaction ids per request, so larger lookups are
+//split into batches. Orders are returned in the same order as the ids
+async fn query_orders_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    txids: &[String],
+    include_trades: bool,
+    userref: Option<u32>,
+) -> Result<Vec<OpenOrder>, KrakenError> {
+    let maximum_txids_per_request = 50;
+    let mut orders = Vec::with_capacity(txids.len());
+    for batch in txids.chunks(maximum_txids_per_request) {
+        let payload = RequestPayload::QueryOrders(QueryOrdersRequestPayload {
+            txids: batch.to_vec(),
+            trades: include_trades,
+            userref: userref,
+        });
+        let mut dto: HashMap<String, OpenOrderDto> =
+            request_private(configuration, credentials, "/private/QueryOrders", &payload).await?;
+        for txid in batch {
+            if let Some(order) = dto.remove(txid) {
+                orders.push(order.to_business(txid)?);
+            }
+        }
+    }
+    Ok(orders)
+}
+
+async fn retrieve_trades_history_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    filter: &TradesHistoryFilter,
+    ofs: Option<u32>,
+) -> Result<(Vec<Trade>, u32), KrakenError> {
+    let payload = RequestPayload::TradesHistory(TradesHistoryRequestPayload {
+        filter: filter.clone(),
+        ofs: ofs,
+    });
+    let dto: TradesHistoryDto = request_private(
+        configuration,
+        credentials,
+        "/private/TradesHistory",
+        &payload,
+    )
+    .await?;
+
+    let mut trades = dto
+        .trades
+        .iter()
+        .map(|(identifier, trade)| trade.to_business(identifier))
+        .collect::<Result<Vec<Trade>, KrakenError>>()?;
+    trades.sort_by(|a, b| b.time().partial_cmp(&a.time()).unwrap_or(Ordering::Equal));
+    Ok((trades, dto.count))
+}
+
+//Same as with orders, but kraken only accepts 20 trade ids per request
+async fn query_trades_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    txids: &[String],
+) -> Result<Vec<Trade>, KrakenError> {
+    let maximum_txids_per_request = 20;
+    let mut trades = Vec::with_capacity(txids.len());
+    for batch in txids.chunks(maximum_txids_per_request) {
+        let payload = RequestPayload::QueryTrades(QueryTradesRequestPayload {
+            txids: batch.to_vec(),
+        });
+        let mut dto: HashMap<String, TradeDto> =
+            request_private(configuration, credentials, "/private/QueryTrades", &payload).await?;
+        for txid in batch {
+            if let Some(trade) = dto.remove(txid) {
+                trades.push(trade.to_business(txid)?);
+            }
+        }
+    }
+    Ok(trades)
+}
+
+async fn retrieve_ledgers_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    filter: &LedgersFilter,
+    ofs: Option<u32>,
+) -> Result<(Vec<LedgerEntry>, u32), KrakenError> {
+    let payload = RequestPayload::Ledgers(LedgersRequestPayload {
+        filter: filter.clone(),
+        ofs: ofs,
+    });
+    let dto: LedgersDto =
+        request_private(configuration, credentials, "/private/Ledgers", &payload).await?;
 
-#[derive(Debug)]
-pub struct KrakenService {
-    configuration: Configuration,
+    let mut entries = dto
+        .ledger
+        .iter()
+        .map(|(identifier, entry)| entry.to_business(identifier))
+        .collect::<Result<Vec<LedgerEntry>, KrakenError>>()?;
+    entries.sort_by(|a, b| b.time().partial_cmp(&a.time()).unwrap_or(Ordering::Equal));
+    Ok((entries, dto.count))
 }
 
-impl KrakenService {
-    pub fn new(configuration: Configuration) -> Self {
-        KrakenService {
-            configuration: configuration,
+async fn query_ledgers_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    ids: &[String],
+) -> Result<Vec<LedgerEntry>, KrakenError> {
+    let maximum_ids_per_request = 20;
+    let mut entries = Vec::with_capacity(ids.len());
+    for batch in ids.chunks(maximum_ids_per_request) {
+        let payload = RequestPayload::QueryLedgers(QueryLedgersRequestPayload {
+            ids: batch.to_vec(),
+        });
+        let mut dto: HashMap<String, LedgerEntryDto> = request_private(
+            configuration,
+            credentials,
+            "/private/QueryLedgers",
+            &payload,
+        )
+        .await?;
+        for id in batch {
+            if let Some(entry) = dto.remove(id) {
+                entries.push(entry.to_business(id)?);
+            }
         }
     }
+    Ok(entries)
 }
 
-impl KrakenService {
-    pub async fn retrieve_server_time(&self) -> Result<ServerTime, std::io::Error> {
-        retrieve_server_time_impl(&self.configuration).await
-    }
+async fn retrieve_balance_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+) -> Result<Balances, KrakenError> {
+    let payload = RequestPayload::Balance(BalanceRequestPayload {});
+    let dto: BalancesDto =
+        request_private(configuration, credentials, "/private/Balance", &payload).await?;
 
-    pub async fn retrieve_xbtusd_pair(&self) -> Result<XbtUsd, std::io::Error> {
-        retrieve_xbtusd_pair_impl(&self.configuration).await
-    }
+    dto.to_business()
+}
 
-    pub async fn retrieve_open_orders(
-        &self,
-        credentials: &Credentials,
-    ) -> Result<Vec<OpenOrder>, std::io::Error> {
-        retrieve_open_orders_impl(&self.configuration, credentials).await
-    }
+async fn retrieve_extended_balance_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+) -> Result<Balances, KrakenError> {
+    let payload = RequestPayload::ExtendedBalance(ExtendedBalanceRequestPayload {});
+    let dto: ExtendedBalancesDto =
+        request_private(configuration, credentials, "/private/BalanceEx", &payload).await?;
+
+    dto.to_business()
 }
 
-async fn retrieve_server_time_impl(
+async fn retrieve_trade_balance_impl(
     configuration: &Configuration,
-) -> Result<ServerTime, std::io::Error> {
-    let client = reqwest::Client::new();
-    let url = format!("{}{}", configuration.base_url, String::from("/public/Time"));
-    let response_json = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| std::io::Error::new(ErrorKind::NotFound, e.to_string()))?
-        .text()
-        .await
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+    credentials: &Credentials,
+    asset: Option<&String>,
+) -> Result<TradeBalance, KrakenError> {
+    let payload = RequestPayload::TradeBalance(TradeBalanceRequestPayload {
+        asset: asset.cloned(),
+    });
+    let dto: TradeBalanceDto = request_private(
+        configuration,
+        credentials,
+        "/private/TradeBalance",
+        &payload,
+    )
+    .await?;
+
+    dto.to_business()
+}
 
-    let dto: ServerTimeDto = serde_json::from_str(&response_json)
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+async fn retrieve_trade_volume_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    pairs: &[String],
+) -> Result<TradeVolume, KrakenError> {
+    let payload = RequestPayload::TradeVolume(TradeVolumeRequestPayload {
+        pairs: pairs.to_vec(),
+    });
+    let dto: TradeVolumeDto =
+        request_private(configuration, credentials, "/private/TradeVolume", &payload).await?;
 
     dto.to_business()
 }
 
-async fn retrieve_xbtusd_pair_impl(
+async fn retrieve_deposit_methods_impl(
     configuration: &Configuration,
-) -> Result<XbtUsd, std::io::Error> {
-    let client = reqwest::Client::new();
-    let url = format!(
-        "{}{}",
-        configuration.base_url,
-        String::from("/public/AssetPairs?pair=XXBTZUSD")
-    );
-    let response_json = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| std::io::Error::new(ErrorKind::NotFound, e.to_string()))?
-        .text()
-        .await
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+    credentials: &Credentials,
+    asset: &String,
+) -> Result<Vec<DepositMethod>, KrakenError> {
+    let payload = RequestPayload::DepositMethods(DepositMethodsRequestPayload {
+        asset: asset.clone(),
+    });
+    let dto: Vec<DepositMethodDto> = request_private(
+        configuration,
+        credentials,
+        "/private/DepositMethods",
+        &payload,
+    )
+    .await?;
+
+    dto.iter()
+        .map(|method| method.to_business())
+        .collect::<Result<Vec<DepositMethod>, KrakenError>>()
+}
+
+//Asking for a new address generates one on every call, so that request is
+//treated like any other call that changes the account
+async fn retrieve_deposit_addresses_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    asset: &String,
+    method: &String,
+    new: bool,
+) -> Result<Vec<DepositAddress>, KrakenError> {
+    let payload = RequestPayload::DepositAddresses(DepositAddressesRequestPayload {
+        asset: asset.clone(),
+        method: method.clone(),
+        new: new,
+    });
+    let path = "/private/DepositAddresses";
+    let dto: Vec<DepositAddressDto> = if new {
+        request_private_mutation(configuration, credentials, path, &payload).await?
+    } else {
+        request_private(configuration, credentials, path, &payload).await?
+    };
+
+    dto.iter()
+        .map(|address| address.to_business())
+        .collect::<Result<Vec<DepositAddress>, KrakenError>>()
+}
 
-    let dto: XbtUsdPairDto = serde_json::from_str(&response_json)
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+async fn retrieve_deposit_status_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    asset: Option<&String>,
+    method: Option<&String>,
+) -> Result<Vec<Deposit>, KrakenError> {
+    let payload = RequestPayload::DepositStatus(DepositStatusRequestPayload {
+        asset: asset.cloned(),
+        method: method.cloned(),
+    });
+    let dto: Vec<DepositDto> = request_private(
+        configuration,
+        credentials,
+        "/private/DepositStatus",
+        &payload,
+    )
+    .await?;
+
+    dto.iter()
+        .map(|deposit| deposit.to_business())
+        .collect::<Result<Vec<Deposit>, KrakenError>>()
+}
+
+async fn retrieve_withdrawal_info_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    asset: &String,
+    key: &String,
+    amount: f64,
+) -> Result<WithdrawalInfo, KrakenError> {
+    let payload = RequestPayload::WithdrawInfo(WithdrawInfoRequestPayload {
+        asset: asset.clone(),
+        key: key.clone(),
+        amount: amount,
+    });
+    let dto: WithdrawInfoDto = request_private(
+        configuration,
+        credentials,
+        "/private/WithdrawInfo",
+        &payload,
+    )
+    .await?;
+
+    dto.to_business(asset, key)
+}
+
+async fn withdraw_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    request: &WithdrawalRequest,
+) -> Result<String, KrakenError> {
+    let _ = configuration.ensure_executable("withdrawals", "sent")?;
+    let payload = RequestPayload::Withdraw(WithdrawRequestPayload {
+        request: request.clone(),
+    });
+    let dto: WithdrawDto =
+        request_private_mutation(configuration, credentials, "/private/Withdraw", &payload).await?;
+
+    Ok(dto.refid)
+}
+
+async fn retrieve_withdrawal_status_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    asset: Option<&String>,
+    method: Option<&String>,
+) -> Result<Vec<Withdrawal>, KrakenError> {
+    let payload = RequestPayload::WithdrawStatus(WithdrawStatusRequestPayload {
+        asset: asset.cloned(),
+        method: method.cloned(),
+    });
+    let dto: Vec<WithdrawalDto> = request_private(
+        configuration,
+        credentials,
+        "/private/WithdrawStatus",
+        &payload,
+    )
+    .await?;
+
+    dto.iter()
+        .map(|withdrawal| withdrawal.to_business())
+        .collect::<Result<Vec<Withdrawal>, KrakenError>>()
+}
+
+async fn cancel_withdrawal_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    asset: &String,
+    refid: &String,
+) -> Result<bool, KrakenError> {
+    let _ = configuration.ensure_executable("withdrawals", "cancelled")?;
+    let payload = RequestPayload::WithdrawCancel(WithdrawCancelRequestPayload {
+        asset: asset.clone(),
+        refid: refid.clone(),
+    });
+    request_private_mutation(
+        configuration,
+        credentials,
+        "/private/WithdrawCancel",
+        &payload,
+    )
+    .await
+}
+
+async fn wallet_transfer_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    transfer: &WalletTransfer,
+) -> Result<String, KrakenError> {
+    let _ = configuration.ensure_executable("transfers", "made")?;
+    let payload = RequestPayload::WalletTransfer(WalletTransferRequestPayload {
+        transfer: transfer.clone(),
+    });
+    let dto: WalletTransferDto = request_private_mutation(
+        configuration,
+        credentials,
+        "/private/WalletTransfer",
+        &payload,
+    )
+    .await?;
+
+    Ok(dto.refid)
+}
+
+async fn account_transfer_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    transfer: &AccountTransfer,
+) -> Result<AccountTransferConfirmation, KrakenError> {
+    let _ = configuration.ensure_executable("transfers", "made")?;
+    let payload = RequestPayload::AccountTransfer(AccountTransferRequestPayload {
+        transfer: transfer.clone(),
+    });
+    let dto: AccountTransferDto = request_private_mutation(
+        configuration,
+        credentials,
+        "/private/AccountTransfer",
+        &payload,
+    )
+    .await?;
+
+    Ok(dto.to_business())
+}
+
+async fn create_subaccount_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    subaccount: &Subaccount,
+) -> Result<bool, KrakenError> {
+    let _ = configuration.ensure_executable("subaccounts", "created")?;
+    let payload = RequestPayload::CreateSubaccount(CreateSubaccountRequestPayload {
+        subaccount: subaccount.clone(),
+    });
+    request_private_mutation(
+        configuration,
+        credentials,
+        "/private/CreateSubaccount",
+        &payload,
+    )
+    .await
+}
+
+//Follows the cursor until kraken stops returning one, lock types are filtered
+//locally rather than sent to kraken
+async fn retrieve_earn_strategies_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    asset: Option<&String>,
+    lock_types: &[LockType],
+) -> Result<Vec<EarnStrategy>, KrakenError> {
+    let mut strategies = vec![];
+    let mut cursor: Option<String> = None;
+    loop {
+        let payload = RequestPayload::EarnStrategies(EarnStrategiesRequestPayload {
+            asset: asset.cloned(),
+            cursor: cursor.clone(),
+        });
+        let dto: EarnStrategiesDto = request_private(
+            configuration,
+            credentials,
+            "/private/Earn/Strategies",
+            &payload,
+        )
+        .await?;
+        for strategy in dto.items.iter() {
+            let strategy = strategy.to_business()?;
+            if lock_types.is_empty() || lock_types.contains(&strategy.lock_type()) {
+                strategies.push(strategy);
+            }
+        }
+        match dto.next_cursor {
+            Some(next_cursor)
+                if !next_cursor.is_empty() && Some(&next_cursor) != cursor.as_ref() =>
+            {
+                cursor = Some(next_cursor)
+            }
+            _ => break,
+        }
+    }
+
+    Ok(strategies)
+}
+
+async fn retrieve_earn_allocations_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    converted_asset: Option<&String>,
+    hide_zero_allocations: bool,
+) -> Result<EarnAllocations, KrakenError> {
+    let payload = RequestPayload::EarnAllocations(EarnAllocationsRequestPayload {
+        converted_asset: converted_asset.cloned(),
+        hide_zero_allocations: hide_zero_allocations,
+    });
+    let dto: EarnAllocationsDto = request_private(
+        configuration,
+        credentials,
+        "/private/Earn/Allocations",
+        &payload,
+    )
+    .await?;
 
     dto.to_business()
 }
 
-async fn retrieve_open_orders_impl(
+async fn allocate_earn_impl(
     configuration: &Configuration,
     credentials: &Credentials,
-) -> Result<Vec<OpenOrder>, std::io::Error> {
-    let payload = RequestPayload::OpenOrders(OpenOrdersRequestPayload {});
-    let uri = uri::new(String::from("/0/private/OpenOrders"));
-    let nonce = nonce::new()?;
-    let web_request = web_request::new(payload, uri, nonce, credentials.clone());
-    let url = format!(
-        "{}{}",
-        configuration.base_url,
-        String::from("/private/OpenOrders")
-    );
-    let response_json = request_open_orders(&url, credentials, &web_request, configuration)
-        .await
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+    request: &EarnRequest,
+) -> Result<bool, KrakenError> {
+    let _ = configuration.ensure_executable("earn allocations", "made")?;
+    let payload = RequestPayload::EarnAllocate(EarnAllocateRequestPayload {
+        request: request.clone(),
+    });
+    request_private_mutation(
+        configuration,
+        credentials,
+        "/private/Earn/Allocate",
+        &payload,
+    )
+    .await
+}
+
+async fn deallocate_earn_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    request: &EarnRequest,
+) -> Result<bool, KrakenError> {
+    let _ = configuration.ensure_executable("earn allocations", "withdrawn")?;
+    let payload = RequestPayload::EarnDeallocate(EarnDeallocateRequestPayload {
+        request: request.clone(),
+    });
+    request_private_mutation(
+        configuration,
+        credentials,
+        "/private/Earn/Deallocate",
+        &payload,
+    )
+    .await
+}
+
+async fn retrieve_earn_allocate_status_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    strategy_id: &String,
+) -> Result<bool, KrakenError> {
+    let payload = RequestPayload::EarnAllocateStatus(EarnAllocateStatusRequestPayload {
+        strategy_id: strategy_id.clone(),
+    });
+    let dto: EarnStatusDto = request_private(
+        configuration,
+        credentials,
+        "/private/Earn/AllocateStatus",
+        &payload,
+    )
+    .await?;
 
-    let dto: OpenOrdersDto = serde_json::from_str(&response_json)?;
+    Ok(dto.pending)
+}
+
+async fn retrieve_earn_deallocate_status_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    strategy_id: &String,
+) -> Result<bool, KrakenError> {
+    let payload = RequestPayload::EarnDeallocateStatus(EarnDeallocateStatusRequestPayload {
+        strategy_id: strategy_id.clone(),
+    });
+    let dto: EarnStatusDto = request_private(
+        configuration,
+        credentials,
+        "/private/Earn/DeallocateStatus",
+        &payload,
+    )
+    .await?;
+
+    Ok(dto.pending)
+}
+
+async fn add_export_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    request: &ExportRequest,
+) -> Result<String, KrakenError> {
+    let payload = RequestPayload::AddExport(AddExportRequestPayload {
+        request: request.clone(),
+    });
+    let dto: AddExportDto =
+        request_private_mutation(configuration, credentials, "/private/AddExport", &payload)
+            .await?;
+
+    Ok(dto.id)
+}
+
+async fn retrieve_export_status_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    report: ReportType,
+) -> Result<Vec<Export>, KrakenError> {
+    let payload = RequestPayload::ExportStatus(ExportStatusRequestPayload { report: report });
+    let dto: Vec<ExportDto> = request_private(
+        configuration,
+        credentials,
+        "/private/ExportStatus",
+        &payload,
+    )
+    .await?;
+
+    dto.iter()
+        .map(|export| export.to_business())
+        .collect::<Result<Vec<Export>, KrakenError>>()
+}
+
+async fn retrieve_export_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    export: &Export,
+    report: ReportType,
+) -> Result<Vec<u8>, KrakenError> {
+    if export.report() != report {
+        return Err(KrakenError::Validation(validation_error::new(&format!(
+            "export {} is not a {} report",
+            export.id(),
+            report.value()
+        ))));
+    }
+    if !export.is_ready() {
+        return Err(KrakenError::Validation(validation_error::new(&format!(
+            "export {} has not been processed yet",
+            export.id()
+        ))));
+    }
+    let payload = RequestPayload::RetrieveExport(RetrieveExportRequestPayload {
+        id: export.id().clone(),
+    });
+
+    request_private_download(
+        configuration,
+        credentials,
+        "/private/RetrieveExport",
+        &payload,
+    )
+    .await
+}
+
+async fn remove_export_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    id: &String,
+    removal: RemovalType,
+) -> Result<bool, KrakenError> {
+    let payload = RequestPayload::RemoveExport(RemoveExportRequestPayload {
+        id: id.clone(),
+        removal: removal,
+    });
+    let dto: RemoveExportDto = request_private_mutation(
+        configuration,
+        credentials,
+        "/private/RemoveExport",
+        &payload,
+    )
+    .await?;
+
+    Ok(dto.to_business())
+}
+
+async fn add_order_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    order: &NewOrder,
+    validate: bool,
+) -> Result<OrderConfirmation, KrakenError> {
+    let validate = configuration.validate_only(validate);
+    let payload = RequestPayload::AddOrder(AddOrderRequestPayload {
+        order: order.clone(),
+        validate: validate,
+    });
+    let dto: AddOrderDto =
+        request_private_mutation(configuration, credentials, "/private/AddOrder", &payload).await?;
+
+    dto.to_business(validate)
+}
+
+async fn add_order_batch_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    pair: &AssetPair,
+    orders: Vec<NewOrderBuilder>,
+    deadline: Option<DateTime<Utc>>,
+    validate: bool,
+) -> Result<Vec<Result<OrderConfirmation, KrakenError>>, KrakenError> {
+    let batch = order_batch::new(pair, orders, deadline)?;
+    let validate = configuration.validate_only(validate);
+    let payload = RequestPayload::AddOrderBatch(AddOrderBatchRequestPayload {
+        batch: batch,
+        validate: validate,
+    });
+    let dto: AddOrderBatchDto = request_private_mutation(
+        configuration,
+        credentials,
+        "/private/AddOrderBatch",
+        &payload,
+    )
+    .await?;
+
+    Ok(dto.to_business(validate))
+}
+
+async fn edit_order_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    edit: &OrderEdit,
+    validate: bool,
+) -> Result<OrderEditConfirmation, KrakenError> {
+    let validate = configuration.validate_only(validate);
+    let payload = RequestPayload::EditOrder(EditOrderRequestPayload {
+        edit: edit.clone(),
+        validate: validate,
+    });
+    let dto: EditOrderDto =
+        request_private_mutation(configuration, credentials, "/private/EditOrder", &payload)
+            .await?;
+
+    dto.to_business(validate)
+}
+
+async fn amend_order_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    edit: &OrderEdit,
+) -> Result<OrderEditConfirmation, KrakenError> {
+    let _ = configuration.ensure_executable("orders", "amended")?;
+    let _ = edit.validate_amendable()?;
+    let payload = RequestPayload::AmendOrder(AmendOrderRequestPayload { edit: edit.clone() });
+    let dto: AmendOrderDto =
+        request_private_mutation(configuration, credentials, "/private/AmendOrder", &payload)
+            .await?;
+
+    dto.to_business(edit.reference().value())
+}
+
+async fn cancel_order_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    reference: &OrderReference,
+) -> Result<OrderCancellation, KrakenError> {
+    let _ = configuration.ensure_executable("orders", "cancelled")?;
+    let payload = RequestPayload::CancelOrder(CancelOrderRequestPayload {
+        reference: reference.clone(),
+    });
+    let dto: CancelOrderDto =
+        request_private_mutation(configuration, credentials, "/private/CancelOrder", &payload)
+            .await?;
+
+    Ok(dto.to_business())
+}
+
+async fn cancel_order_batch_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    references: &[OrderReference],
+) -> Result<OrderCancellation, KrakenError> {
+    let _ = configuration.ensure_executable("orders", "cancelled")?;
+    let batch = order_cancellation::new_batch(references.to_vec())?;
+    let payload = RequestPayload::CancelOrderBatch(CancelOrderBatchRequestPayload { batch: batch });
+    let dto: CancelOrderDto = request_private_mutation(
+        configuration,
+        credentials,
+        "/private/CancelOrderBatch",
+        &payload,
+    )
+    .await?;
+
+    Ok(dto.to_business())
+}
+
+async fn cancel_all_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+) -> Result<OrderCancellation, KrakenError> {
+    let _ = configuration.ensure_executable("orders", "cancelled")?;
+    let payload = RequestPayload::CancelAll(CancelAllRequestPayload {});
+    let dto: CancelOrderDto =
+        request_private_mutation(configuration, credentials, "/private/CancelAll", &payload)
+            .await?;
+
+    Ok(dto.to_business())
+}
 
-    dto.result
-        .open
-        .unwrap_or_else(|| HashMap::new())
-        .into_iter()
-        .map(|(identifier, order)| order.to_business(&identifier))
-        .collect::<Result<Vec<OpenOrder>, std::io::Error>>()
+//A timeout of zero disarms the timer
+async fn cancel_all_orders_after_impl(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    timeout: Duration,
+) -> Result<DeadManSwitch, KrakenError> {
+    let _ = configuration.ensure_executable("orders", "scheduled for cancellation")?;
+    let payload = RequestPayload::CancelAllOrdersAfter(CancelAllOrdersAfterRequestPayload {
+        timeout: timeout.as_secs(),
+    });
+    let dto: CancelAllOrdersAfterDto = request_private_mutation(
+        configuration,
+        credentials,
+        "/private/CancelAllOrdersAfter",
+        &payload,
+    )
+    .await?;
+
+    dto.to_business()
+}
+
+async fn request_private<T: DeserializeOwned>(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    path: &str,
+    payload: &RequestPayload,
+) -> Result<T, KrakenError> {
+    request_private_with_retry(
+        configuration,
+        credentials,
+        path,
+        payload,
+        KrakenError::is_transient,
+    )
+    .await
+}
+
+//Calls that change the state of the account are only retried when kraken
+//refused them outright, since a transport error or an unavailable service could
+//hide a request that was executed anyway
+async fn request_private_mutation<T: DeserializeOwned>(
+    configuration: &Configuration,
+    credentials: &Credentials,
+    path: &str,
+    payload: &RequestPayload,
+) -> Result<T, KrakenError> {
+    request_private_with_retry(
+        configuration,
+        credentials,
+        path,
+        payload,
+        KrakenError::is_transient_rejection,
+    )
+    .await
 }
 
-async fn request_open_orders(
-    url: &String,
+async fn request_private_with_retry<T: DeserializeOwned>(
+    configuration: &Configuration,
     credentials: &Credentials,
-    web_request: &WebRequest,
+    path: &str,
+    payload: &RequestPayload,
+    is_retryable: fn(&KrakenError) -> bool,
+) -> Result<T, KrakenError> {
+    let client = reqwest::Client::new();
+    retry(retry_policy(configuration), || async {
+        send_private_request(&client, configuration, credentials, path, payload)
+            .await
+            .map_err(|e| to_backoff_error(e, is_retryable))
+    })
+    .await
+}
+
+async fn request_private_download(
     configuration: &Configuration,
-) -> Result<String, String> {
+    credentials: &Credentials,
+    path: &str,
+    payload: &RequestPayload,
+) -> Result<Vec<u8>, KrakenError> {
     let client = reqwest::Client::new();
+    retry(retry_policy(configuration), || async {
+        send_private_download(&client, configuration, credentials, path, payload)
+            .await
+            .map_err(|e| to_backoff_error(e, KrakenError::is_transient))
+    })
+    .await
+}
+
+fn retry_policy(configuration: &Configuration) -> ExponentialBackoff {
     let mut backoff = ExponentialBackoff::default();
     backoff.max_elapsed_time = Some(configuration.retry_max_interval);
     backoff.multiplier = configuration.retry_multiplier;
     backoff.initial_interval = configuration.retry_initial_interval;
-    retry(backoff, || async {
-        match send_open_orders_request(&client, url, web_request, credentials).await {
-            Ok(json) => Ok(json),
-            Err(e) => Err(backoff::Error::transient(e.to_string())),
-        }
-    })
-    .await
+    backoff
+}
+
+fn to_backoff_error(
+    error: KrakenError,
+    is_retryable: fn(&KrakenError) -> bool,
+) -> backoff::Error<KrakenError> {
+    if is_retryable(&error) {
+        backoff::Error::transient(error)
+    } else {
+        backoff::Error::permanent(error)
+    }
 }
 
-async fn send_open_orders_request(
+//A new nonce is generated for every attempt, since kraken rejects reused ones
+async fn send_private_request<T: DeserializeOwned>(
     client: &reqwest::Client,
-    url: &String,
-    web_request: &WebRequest,
+    configuration: &Configuration,
+    credentials: &Credentials,
+    path: &str,
+    payload: &RequestPayload,
+) -> Result<T, KrakenError> {
+    let response_json = send_signed_request(client, configuration, credentials, path, payload)
+        .await?
+        .text()
+        .await
+        .map_err(|e| KrakenError::Transport(e.to_string()))?;
+
+    decode(&response_json)
+}
+
+//Kraken sends the export as a zip archive and only answers with json when it fails
+async fn send_private_download(
+    client: &reqwest::Client,
+    configuration: &Configuration,
+    credentials: &Credentials,
+    path: &str,
+    payload: &RequestPayload,
+) -> Result<Vec<u8>, KrakenError> {
+    let archive = send_signed_request(client, configuration, credentials, path, payload)
+        .await?
+        .bytes()
+        .await
+        .map_err(|e| KrakenError::Transport(e.to_string()))?;
+
+    if archive.starts_with(b"PK") {
+        Ok(archive.to_vec())
+    } else {
+        let _: serde_json::Value = decode(&String::from_utf8_lossy(&archive))?;
+        Err(KrakenError::Decoding(String::from(
+            "the export is not a zip archive",
+        )))
+    }
+}
+
+async fn send_signed_request(
+    client: &reqwest::Client,
+    configuration: &Configuration,
     credentials: &Credentials,
-) -> Result<String, std::io::Error> {
-    let result_field_name = "result";
-    let text = client
-        .post(url)
+    path: &str,
+    payload: &RequestPayload,
+) -> Result<reqwest::Response, KrakenError> {
+    let uri = uri::new(format!("/0{}", path));
+    let nonce = nonce::new()?;
+    let web_request = web_request::new(payload.clone(), uri, nonce, credentials.clone());
+    let url = format!("{}{}", configuration.base_url, path);
+    client
+        .post(&url)
         .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
         .header("API-Sign", api_sign(&web_request)?)
         .header("API-Key", credentials.api_key().clone())
         .body(encoded_payload(&web_request)?)
         .send()
         .await
-        .map_err(|e| std::io::Error::new(ErrorKind::NotFound, e.to_string()))?
-        .text()
-        .await
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+        .map_err(|e| KrakenError::Transport(e.to_string()))
+}
 
-    if text.contains(result_field_name) {
-        Ok(text)
-    } else {
-        Err(std::io::Error::new(
-            ErrorKind::InvalidData,
-            "error retrieving open orders",
-        ))
-    }
+fn decode<T: DeserializeOwned>(response_json: &str) -> Result<T, KrakenError> {
+    let dto: ResponseDto<T> =
+        serde_json::from_str(response_json).map_err(|e| KrakenError::Decoding(e.to_string()))?;
+
+    dto.into_result()
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Configuration {
     base_url: String,
     retry_initial_interval: Duration,
     retry_multiplier: f64,
     retry_max_interval: Duration,
+    validate_only: bool,
 }
 
 impl Configuration {
@@ -190,12 +1818,34 @@ impl Configuration {
         retry_initial_interval: Duration,
         retry_multiplier: f64,
         retry_max_interval: Duration,
+        validate_only: bool,
     ) -> Self {
         Self {
             base_url: base_url,
             retry_initial_interval: retry_initial_interval,
             retry_multiplier: retry_multiplier,
             retry_max_interval: retry_max_interval,
+            validate_only: validate_only,
+        }
+    }
+
+    //A validate-only configuration can't be overridden per call, so an environment
+    //set up that way never places real orders
+    fn validate_only(&self, validate: bool) -> bool {
+        self.validate_only || validate
+    }
+
+    //Amends, cancellations, withdrawals, transfers, subaccounts and earn allocations
+    //have no validate-only mode, so they're refused outright when the configuration
+    //must never touch the real account
+    fn ensure_executable(&self, subject: &str, action: &str) -> Result<(), KrakenError> {
+        if self.validate_only {
+            Err(KrakenError::Validation(validation_error::new(&format!(
+                "{} cannot be {} in validate-only mode",
+                subject, action
+            ))))
+        } else {
+            Ok(())
         }
     }
 }
@@ -1,17 +1,16 @@
-use std::{
-    io::ErrorKind,
-    time::{SystemTime, UNIX_EPOCH},
-};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::error::KrakenError;
 
 pub struct Nonce {
     pub(crate) value: u64,
 }
 
-pub fn new() -> Result<Nonce, std::io::Error> {
+pub fn new() -> Result<Nonce, KrakenError> {
     Ok(Nonce {
         value: SystemTime::now()
             .duration_since(UNIX_EPOCH)
-            .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?
+            .map_err(|e| KrakenError::Signing(e.to_string()))?
             .as_millis() as u64,
     })
 }
@@ -0,0 +1,161 @@
+use std::future::Future;
+
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::error::KrakenError;
+
+//Keeps requesting pages from the last cursor returned by the api, and stops once
+//a page comes back empty or the cursor stops moving
+pub(crate) fn follow_cursor<T, F, Fut>(
+    since: Option<u64>,
+    mut fetch_page: F,
+) -> impl Stream<Item = Result<T, KrakenError>>
+where
+    F: FnMut(Option<u64>) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, u64), KrakenError>>,
+{
+    stream::unfold(Some(since), move |state| {
+        let page = state.map(|cursor| (cursor, fetch_page(cursor)));
+        async move {
+            let (cursor, page) = page?;
+            match page.await {
+                Ok((items, last)) => {
+                    let is_caught_up = items.is_empty() || cursor == Some(last);
+                    let next_state = if is_caught_up { None } else { Some(Some(last)) };
+                    Some((Ok(items), next_state))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+//Keeps requesting pages at increasing offsets until the total count reported by
+//the api has been reached
+pub(crate) fn follow_offset<T, F, Fut>(
+    mut fetch_page: F,
+) -> impl Stream<Item = Result<T, KrakenError>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, u32), KrakenError>>,
+{
+    stream::unfold(Some(0), move |state| {
+        let page = state.map(|offset| (offset, fetch_page(offset)));
+        async move {
+            let (offset, page) = page?;
+            match page.await {
+                Ok((items, count)) => {
+                    let next_offset = offset + items.len() as u32;
+                    let is_done = items.is_empty() || next_offset >= count;
+                    let next_state = if is_done { None } else { Some(next_offset) };
+                    Some((Ok(items), next_state))
+                }
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+    .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+    .try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, StreamExt};
+
+    use crate::error::KrakenError;
+
+    use super::{follow_cursor, follow_offset};
+
+    #[test]
+    fn should_follow_cursor_stop_once_a_page_is_empty() {
+        //Arrange
+        let pages = [(vec![1, 2], 10), (vec![3], 20), (vec![], 20)];
+        let mut page_index = 0;
+
+        //Act
+        let items = block_on(
+            follow_cursor(None, |_cursor| {
+                let page = pages[page_index].clone();
+                page_index += 1;
+                async move { Ok::<(Vec<i32>, u64), KrakenError>(page) }
+            })
+            .collect::<Vec<Result<i32, KrakenError>>>(),
+        );
+
+        //Assert
+        assert_eq!(
+            items.into_iter().map(|i| i.unwrap()).collect::<Vec<i32>>(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn should_follow_cursor_request_pages_from_the_last_cursor() {
+        //Arrange
+        let mut cursors = vec![];
+
+        //Act
+        let _ = block_on(
+            follow_cursor(Some(5), |cursor| {
+                cursors.push(cursor);
+                let page = match cursor {
+                    Some(5) => (vec![1], 10),
+                    _ => (vec![], 10),
+                };
+                async move { Ok::<(Vec<i32>, u64), KrakenError>(page) }
+            })
+            .collect::<Vec<Result<i32, KrakenError>>>(),
+        );
+
+        //Assert
+        assert_eq!(cursors, vec![Some(5), Some(10)]);
+    }
+
+    #[test]
+    fn should_follow_cursor_stop_after_an_error() {
+        //Arrange
+        let mut calls = 0;
+
+        //Act
+        let items = block_on(
+            follow_cursor(None, |_cursor| {
+                calls += 1;
+                async move {
+                    Err::<(Vec<i32>, u64), KrakenError>(KrakenError::Transport(String::from(
+                        "connection reset",
+                    )))
+                }
+            })
+            .collect::<Vec<Result<i32, KrakenError>>>(),
+        );
+
+        //Assert
+        assert_eq!(items.len(), 1);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn should_follow_offset_stop_once_the_count_is_reached() {
+        //Arrange
+        let mut offsets = vec![];
+
+        //Act
+        let items = block_on(
+            follow_offset(|offset| {
+                offsets.push(offset);
+                let page = match offset {
+                    0 => vec![1, 2],
+                    _ => vec![3],
+                };
+                async move { Ok::<(Vec<i32>, u32), KrakenError>((page, 3)) }
+            })
+            .collect::<Vec<Result<i32, KrakenError>>>(),
+        );
+
+        //Assert
+        assert_eq!(items.len(), 3);
+        assert_eq!(offsets, vec![0, 2]);
+    }
+}
@@ -1,11 +1,277 @@
-use crate::nonce::Nonce;
+use lootag_cryptoassignment_domain::{
+    earn::EarnRequest,
+    export::{ExportRequest, RemovalType, ReportType},
+    new_order::{NewOrder, OrderFlag},
+    open_order_description::OrderType,
+    order_batch::OrderBatch,
+    order_cancellation::CancellationBatch,
+    order_edit::{OrderEdit, OrderReference},
+    subaccount::Subaccount,
+    transfer::{AccountTransfer, WalletTransfer},
+    withdrawal::WithdrawalRequest,
+};
 
+use crate::{
+    filter::{ClosedOrdersFilter, LedgersFilter, TradesHistoryFilter},
+    nonce::Nonce,
+};
+
+#[derive(Clone)]
 pub enum RequestPayload {
     OpenOrders(OpenOrdersRequestPayload),
+    Balance(BalanceRequestPayload),
+    ExtendedBalance(ExtendedBalanceRequestPayload),
+    TradeBalance(TradeBalanceRequestPayload),
+    ClosedOrders(ClosedOrdersRequestPayload),
+    QueryOrders(QueryOrdersRequestPayload),
+    TradesHistory(TradesHistoryRequestPayload),
+    QueryTrades(QueryTradesRequestPayload),
+    Ledgers(LedgersRequestPayload),
+    QueryLedgers(QueryLedgersRequestPayload),
+    TradeVolume(TradeVolumeRequestPayload),
+    AddOrder(AddOrderRequestPayload),
+    AddOrderBatch(AddOrderBatchRequestPayload),
+    EditOrder(EditOrderRequestPayload),
+    AmendOrder(AmendOrderRequestPayload),
+    CancelOrder(CancelOrderRequestPayload),
+    CancelOrderBatch(CancelOrderBatchRequestPayload),
+    CancelAll(CancelAllRequestPayload),
+    CancelAllOrdersAfter(CancelAllOrdersAfterRequestPayload),
+    OpenPositions(OpenPositionsRequestPayload),
+    DepositMethods(DepositMethodsRequestPayload),
+    DepositAddresses(DepositAddressesRequestPayload),
+    DepositStatus(DepositStatusRequestPayload),
+    WithdrawInfo(WithdrawInfoRequestPayload),
+    Withdraw(WithdrawRequestPayload),
+    WithdrawStatus(WithdrawStatusRequestPayload),
+    WithdrawCancel(WithdrawCancelRequestPayload),
+    WalletTransfer(WalletTransferRequestPayload),
+    AccountTransfer(AccountTransferRequestPayload),
+    CreateSubaccount(CreateSubaccountRequestPayload),
+    EarnStrategies(EarnStrategiesRequestPayload),
+    EarnAllocations(EarnAllocationsRequestPayload),
+    EarnAllocate(EarnAllocateRequestPayload),
+    EarnDeallocate(EarnDeallocateRequestPayload),
+    EarnAllocateStatus(EarnAllocateStatusRequestPayload),
+    EarnDeallocateStatus(EarnDeallocateStatusRequestPayload),
+    AddExport(AddExportRequestPayload),
+    ExportStatus(ExportStatusRequestPayload),
+    RetrieveExport(RetrieveExportRequestPayload),
+    RemoveExport(RemoveExportRequestPayload),
 }
 
+#[derive(Clone)]
 pub struct OpenOrdersRequestPayload {}
 
+#[derive(Clone)]
+pub struct BalanceRequestPayload {}
+
+#[derive(Clone)]
+pub struct ExtendedBalanceRequestPayload {}
+
+#[derive(Clone)]
+pub struct TradeBalanceRequestPayload {
+    pub asset: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct ClosedOrdersRequestPayload {
+    pub filter: ClosedOrdersFilter,
+    pub ofs: Option<u32>,
+}
+
+#[derive(Clone)]
+pub struct QueryOrdersRequestPayload {
+    pub txids: Vec<String>,
+    pub trades: bool,
+    pub userref: Option<u32>,
+}
+
+#[derive(Clone)]
+pub struct TradesHistoryRequestPayload {
+    pub filter: TradesHistoryFilter,
+    pub ofs: Option<u32>,
+}
+
+#[derive(Clone)]
+pub struct QueryTradesRequestPayload {
+    pub txids: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct LedgersRequestPayload {
+    pub filter: LedgersFilter,
+    pub ofs: Option<u32>,
+}
+
+#[derive(Clone)]
+pub struct QueryLedgersRequestPayload {
+    pub ids: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct TradeVolumeRequestPayload {
+    pub pairs: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct AddOrderRequestPayload {
+    pub order: NewOrder,
+    pub validate: bool,
+}
+
+#[derive(Clone)]
+pub struct AddOrderBatchRequestPayload {
+    pub batch: OrderBatch,
+    pub validate: bool,
+}
+
+#[derive(Clone)]
+pub struct EditOrderRequestPayload {
+    pub edit: OrderEdit,
+    pub validate: bool,
+}
+
+#[derive(Clone)]
+pub struct AmendOrderRequestPayload {
+    pub edit: OrderEdit,
+}
+
+#[derive(Clone)]
+pub struct CancelOrderRequestPayload {
+    pub reference: OrderReference,
+}
+
+#[derive(Clone)]
+pub struct CancelOrderBatchRequestPayload {
+    pub batch: CancellationBatch,
+}
+
+#[derive(Clone)]
+pub struct CancelAllRequestPayload {}
+
+#[derive(Clone)]
+pub struct CancelAllOrdersAfterRequestPayload {
+    pub timeout: u64,
+}
+
+#[derive(Clone)]
+pub struct OpenPositionsRequestPayload {
+    pub txids: Vec<String>,
+    pub docalcs: bool,
+    pub consolidated: bool,
+}
+
+#[derive(Clone)]
+pub struct DepositMethodsRequestPayload {
+    pub asset: String,
+}
+
+#[derive(Clone)]
+pub struct DepositAddressesRequestPayload {
+    pub asset: String,
+    pub method: String,
+    pub new: bool,
+}
+
+#[derive(Clone)]
+pub struct DepositStatusRequestPayload {
+    pub asset: Option<String>,
+    pub method: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct WithdrawInfoRequestPayload {
+    pub asset: String,
+    pub key: String,
+    pub amount: f64,
+}
+
+#[derive(Clone)]
+pub struct WithdrawRequestPayload {
+    pub request: WithdrawalRequest,
+}
+
+#[derive(Clone)]
+pub struct WithdrawStatusRequestPayload {
+    pub asset: Option<String>,
+    pub method: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct WithdrawCancelRequestPayload {
+    pub asset: String,
+    pub refid: String,
+}
+
+#[derive(Clone)]
+pub struct WalletTransferRequestPayload {
+    pub transfer: WalletTransfer,
+}
+
+#[derive(Clone)]
+pub struct AccountTransferRequestPayload {
+    pub transfer: AccountTransfer,
+}
+
+#[derive(Clone)]
+pub struct CreateSubaccountRequestPayload {
+    pub subaccount: Subaccount,
+}
+
+#[derive(Clone)]
+pub struct EarnStrategiesRequestPayload {
+    pub asset: Option<String>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct EarnAllocationsRequestPayload {
+    pub converted_asset: Option<String>,
+    pub hide_zero_allocations: bool,
+}
+
+#[derive(Clone)]
+pub struct EarnAllocateRequestPayload {
+    pub request: EarnRequest,
+}
+
+#[derive(Clone)]
+pub struct EarnDeallocateRequestPayload {
+    pub request: EarnRequest,
+}
+
+#[derive(Clone)]
+pub struct EarnAllocateStatusRequestPayload {
+    pub strategy_id: String,
+}
+
+#[derive(Clone)]
+pub struct EarnDeallocateStatusRequestPayload {
+    pub strategy_id: String,
+}
+
+#[derive(Clone)]
+pub struct AddExportRequestPayload {
+    pub request: ExportRequest,
+}
+
+#[derive(Clone)]
+pub struct ExportStatusRequestPayload {
+    pub report: ReportType,
+}
+
+#[derive(Clone)]
+pub struct RetrieveExportRequestPayload {
+    pub id: String,
+}
+
+#[derive(Clone)]
+pub struct RemoveExportRequestPayload {
+    pub id: String,
+    pub removal: RemovalType,
+}
+
 pub fn encode(payload: &RequestPayload, nonce: &Nonce, otp: &String) -> String {
     encode_impl(payload, nonce, otp)
 }
@@ -13,9 +279,203 @@ pub fn encode(payload: &RequestPayload, nonce: &Nonce, otp: &String) -> String {
 fn encode_impl(payload: &RequestPayload, nonce: &Nonce, otp: &String) -> String {
     match payload {
         RequestPayload::OpenOrders(orders) => encode_open_orders_payload(orders, nonce, otp),
+        RequestPayload::Balance(_) | RequestPayload::ExtendedBalance(_) => {
+            encode_empty_payload(nonce, otp)
+        }
+        RequestPayload::TradeBalance(trade_balance) => {
+            encode_trade_balance_payload(trade_balance, nonce, otp)
+        }
+        RequestPayload::ClosedOrders(closed_orders) => {
+            encode_closed_orders_payload(closed_orders, nonce, otp)
+        }
+        RequestPayload::QueryOrders(query_orders) => {
+            encode_query_orders_payload(query_orders, nonce, otp)
+        }
+        RequestPayload::TradesHistory(trades_history) => {
+            encode_trades_history_payload(trades_history, nonce, otp)
+        }
+        RequestPayload::QueryTrades(query_trades) => {
+            encode_parameters(nonce, &[("txid", query_trades.txids.join(","))], otp)
+        }
+        RequestPayload::Ledgers(ledgers) => encode_ledgers_payload(ledgers, nonce, otp),
+        RequestPayload::QueryLedgers(query_ledgers) => {
+            encode_parameters(nonce, &[("id", query_ledgers.ids.join(","))], otp)
+        }
+        RequestPayload::TradeVolume(trade_volume) => {
+            encode_trade_volume_payload(trade_volume, nonce, otp)
+        }
+        RequestPayload::AddOrder(add_order) => encode_add_order_payload(add_order, nonce, otp),
+        RequestPayload::AddOrderBatch(add_order_batch) => {
+            encode_add_order_batch_payload(add_order_batch, nonce, otp)
+        }
+        RequestPayload::EditOrder(edit_order) => encode_edit_order_payload(edit_order, nonce, otp),
+        RequestPayload::AmendOrder(amend_order) => {
+            encode_amend_order_payload(amend_order, nonce, otp)
+        }
+        RequestPayload::CancelOrder(cancel_order) => {
+            encode_parameters(nonce, &[("txid", cancel_order.reference.value())], otp)
+        }
+        RequestPayload::CancelOrderBatch(cancel_order_batch) => {
+            encode_cancel_order_batch_payload(cancel_order_batch, nonce, otp)
+        }
+        RequestPayload::CancelAll(_) => encode_empty_payload(nonce, otp),
+        RequestPayload::DepositMethods(deposit_methods) => {
+            encode_parameters(nonce, &[("asset", deposit_methods.asset.clone())], otp)
+        }
+        RequestPayload::DepositAddresses(deposit_addresses) => {
+            encode_deposit_addresses_payload(deposit_addresses, nonce, otp)
+        }
+        RequestPayload::DepositStatus(deposit_status) => {
+            encode_funding_status_payload(&deposit_status.asset, &deposit_status.method, nonce, otp)
+        }
+        RequestPayload::WithdrawInfo(withdraw_info) => encode_parameters(
+            nonce,
+            &[
+                ("asset", withdraw_info.asset.clone()),
+                ("key", withdraw_info.key.clone()),
+                ("amount", withdraw_info.amount.to_string()),
+            ],
+            otp,
+        ),
+        RequestPayload::Withdraw(withdraw) => encode_withdraw_payload(withdraw, nonce, otp),
+        RequestPayload::WithdrawStatus(withdraw_status) => encode_funding_status_payload(
+            &withdraw_status.asset,
+            &withdraw_status.method,
+            nonce,
+            otp,
+        ),
+        RequestPayload::WalletTransfer(wallet_transfer) => {
+            let transfer = &wallet_transfer.transfer;
+            encode_parameters(
+                nonce,
+                &[
+                    ("asset", transfer.asset().clone()),
+                    ("from", String::from(transfer.from().value())),
+                    ("to", String::from(transfer.to().value())),
+                    ("amount", transfer.amount().to_string()),
+                ],
+                otp,
+            )
+        }
+        RequestPayload::AccountTransfer(account_transfer) => {
+            let transfer = &account_transfer.transfer;
+            encode_parameters(
+                nonce,
+                &[
+                    ("asset", transfer.asset().clone()),
+                    ("amount", transfer.amount().to_string()),
+                    ("from", transfer.from().clone()),
+                    ("to", transfer.to().clone()),
+                ],
+                otp,
+            )
+        }
+        RequestPayload::CreateSubaccount(create_subaccount) => encode_parameters(
+            nonce,
+            &[
+                ("username", create_subaccount.subaccount.username().clone()),
+                ("email", create_subaccount.subaccount.email().clone()),
+            ],
+            otp,
+        ),
+        RequestPayload::EarnStrategies(earn_strategies) => {
+            let mut parameters = vec![];
+            if let Some(asset) = &earn_strategies.asset {
+                parameters.push(("asset", asset.clone()));
+            }
+            if let Some(cursor) = &earn_strategies.cursor {
+                parameters.push(("cursor", cursor.clone()));
+            }
+            encode_parameters(nonce, &parameters, otp)
+        }
+        RequestPayload::EarnAllocations(earn_allocations) => {
+            let mut parameters = vec![];
+            if let Some(converted_asset) = &earn_allocations.converted_asset {
+                parameters.push(("converted_asset", converted_asset.clone()));
+            }
+            if earn_allocations.hide_zero_allocations {
+                parameters.push(("hide_zero_allocations", String::from("true")));
+            }
+            encode_parameters(nonce, &parameters, otp)
+        }
+        RequestPayload::EarnAllocate(earn_allocate) => {
+            encode_earn_request(&earn_allocate.request, nonce, otp)
+        }
+        RequestPayload::EarnDeallocate(earn_deallocate) => {
+            encode_earn_request(&earn_deallocate.request, nonce, otp)
+        }
+        RequestPayload::EarnAllocateStatus(earn_allocate_status) => encode_parameters(
+            nonce,
+            &[("strategy_id", earn_allocate_status.strategy_id.clone())],
+            otp,
+        ),
+        RequestPayload::EarnDeallocateStatus(earn_deallocate_status) => encode_parameters(
+            nonce,
+            &[("strategy_id", earn_deallocate_status.strategy_id.clone())],
+            otp,
+        ),
+        RequestPayload::AddExport(add_export) => {
+            encode_add_export_payload(&add_export.request, nonce, otp)
+        }
+        RequestPayload::ExportStatus(export_status) => encode_parameters(
+            nonce,
+            &[("report", String::from(export_status.report.value()))],
+            otp,
+        ),
+        RequestPayload::RetrieveExport(retrieve_export) => {
+            encode_parameters(nonce, &[("id", retrieve_export.id.clone())], otp)
+        }
+        RequestPayload::RemoveExport(remove_export) => encode_parameters(
+            nonce,
+            &[
+                ("id", remove_export.id.clone()),
+                ("type", String::from(remove_export.removal.value())),
+            ],
+            otp,
+        ),
+        RequestPayload::WithdrawCancel(withdraw_cancel) => encode_parameters(
+            nonce,
+            &[
+                ("asset", withdraw_cancel.asset.clone()),
+                ("refid", withdraw_cancel.refid.clone()),
+            ],
+            otp,
+        ),
+        RequestPayload::OpenPositions(open_positions) => {
+            encode_open_positions_payload(open_positions, nonce, otp)
+        }
+        RequestPayload::CancelAllOrdersAfter(cancel_all_orders_after) => encode_parameters(
+            nonce,
+            &[("timeout", cancel_all_orders_after.timeout.to_string())],
+            otp,
+        ),
     }
 }
 
+fn encode_parameters(nonce: &Nonce, parameters: &[(&str, String)], otp: &String) -> String {
+    let parameters = parameters
+        .iter()
+        .map(|(key, value)| format!("&{}={}", url_encode(key), url_encode(value)))
+        .collect::<String>();
+    format!("nonce={}{}&otp={}", nonce.value, parameters, otp)
+}
+
+fn url_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn encode_empty_payload(nonce: &Nonce, otp: &String) -> String {
+    format!("nonce={}&otp={}", nonce.value.to_string(), otp)
+}
+
 fn encode_open_orders_payload(
     _payload: &OpenOrdersRequestPayload,
     nonce: &Nonce,
@@ -24,11 +484,446 @@ fn encode_open_orders_payload(
     format!("nonce={}&trades=true&otp={}", nonce.value.to_string(), otp)
 }
 
+fn encode_trade_balance_payload(
+    payload: &TradeBalanceRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let mut parameters = vec![];
+    if let Some(asset) = &payload.asset {
+        parameters.push(("asset", asset.clone()));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_closed_orders_payload(
+    payload: &ClosedOrdersRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let filter = &payload.filter;
+    let mut parameters = vec![("trades", filter.trades.to_string())];
+    if let Some(userref) = filter.userref {
+        parameters.push(("userref", userref.to_string()));
+    }
+    if let Some(start) = filter.start {
+        parameters.push(("start", start.to_string()));
+    }
+    if let Some(end) = filter.end {
+        parameters.push(("end", end.to_string()));
+    }
+    if let Some(ofs) = payload.ofs {
+        parameters.push(("ofs", ofs.to_string()));
+    }
+    parameters.push(("closetime", String::from(filter.closetime.value())));
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_query_orders_payload(
+    payload: &QueryOrdersRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let mut parameters = vec![
+        ("txid", payload.txids.join(",")),
+        ("trades", payload.trades.to_string()),
+    ];
+    if let Some(userref) = payload.userref {
+        parameters.push(("userref", userref.to_string()));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_trades_history_payload(
+    payload: &TradesHistoryRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let filter = &payload.filter;
+    let mut parameters = vec![("type", String::from(filter.trade_type.value()))];
+    if let Some(start) = filter.start {
+        parameters.push(("start", start.to_string()));
+    }
+    if let Some(end) = filter.end {
+        parameters.push(("end", end.to_string()));
+    }
+    if let Some(ofs) = payload.ofs {
+        parameters.push(("ofs", ofs.to_string()));
+    }
+    parameters.push(("consolidate_taker", filter.consolidate_taker.to_string()));
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_ledgers_payload(payload: &LedgersRequestPayload, nonce: &Nonce, otp: &String) -> String {
+    let filter = &payload.filter;
+    let assets = if filter.assets.is_empty() {
+        String::from("all")
+    } else {
+        filter.assets.join(",")
+    };
+    let entry_type = filter
+        .entry_type
+        .as_ref()
+        .map_or("all", |entry_type| entry_type.value());
+    let mut parameters = vec![("asset", assets), ("type", String::from(entry_type))];
+    if let Some(start) = filter.start {
+        parameters.push(("start", start.to_string()));
+    }
+    if let Some(end) = filter.end {
+        parameters.push(("end", end.to_string()));
+    }
+    if let Some(ofs) = payload.ofs {
+        parameters.push(("ofs", ofs.to_string()));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_trade_volume_payload(
+    payload: &TradeVolumeRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let mut parameters = vec![];
+    if !payload.pairs.is_empty() {
+        parameters.push(("pair", payload.pairs.join(",")));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_open_positions_payload(
+    payload: &OpenPositionsRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let mut parameters = vec![];
+    if !payload.txids.is_empty() {
+        parameters.push(("txid", payload.txids.join(",")));
+    }
+    parameters.push(("docalcs", payload.docalcs.to_string()));
+    if payload.consolidated {
+        parameters.push(("consolidation", String::from("market")));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_deposit_addresses_payload(
+    payload: &DepositAddressesRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let mut parameters = vec![
+        ("asset", payload.asset.clone()),
+        ("method", payload.method.clone()),
+    ];
+    if payload.new {
+        parameters.push(("new", String::from("true")));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+//Deposit and withdrawal status take the same optional filters
+fn encode_funding_status_payload(
+    asset: &Option<String>,
+    method: &Option<String>,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let mut parameters = vec![];
+    if let Some(asset) = asset {
+        parameters.push(("asset", asset.clone()));
+    }
+    if let Some(method) = method {
+        parameters.push(("method", method.clone()));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_add_export_payload(request: &ExportRequest, nonce: &Nonce, otp: &String) -> String {
+    let mut parameters = vec![
+        ("report", String::from(request.report().value())),
+        ("format", String::from(request.format().value())),
+        ("description", request.description().clone()),
+    ];
+    if let Some(start_time) = request.start_time() {
+        parameters.push(("starttm", start_time.to_string()));
+    }
+    if let Some(end_time) = request.end_time() {
+        parameters.push(("endtm", end_time.to_string()));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_earn_request(request: &EarnRequest, nonce: &Nonce, otp: &String) -> String {
+    encode_parameters(
+        nonce,
+        &[
+            ("amount", request.amount().to_string()),
+            ("strategy_id", request.strategy_id().clone()),
+        ],
+        otp,
+    )
+}
+
+fn encode_withdraw_payload(
+    payload: &WithdrawRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let request = &payload.request;
+    let mut parameters = vec![
+        ("asset", request.asset().clone()),
+        ("key", request.key().clone()),
+        ("amount", request.format_amount()),
+    ];
+    if let Some(address) = request.address() {
+        parameters.push(("address", address.clone()));
+    }
+    if let Some(max_fee) = request.format_max_fee() {
+        parameters.push(("max_fee", max_fee));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn encode_add_order_payload(
+    payload: &AddOrderRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let mut parameters = vec![(String::from("pair"), payload.order.pair().clone())];
+    parameters.extend(order_parameters(&payload.order, ""));
+    if payload.validate {
+        parameters.push((String::from("validate"), String::from("true")));
+    }
+    encode_owned_parameters(nonce, &parameters, otp)
+}
+
+fn encode_add_order_batch_payload(
+    payload: &AddOrderBatchRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let batch = &payload.batch;
+    let mut parameters = vec![(String::from("pair"), batch.pair().clone())];
+    for (index, order) in batch.orders().iter().enumerate() {
+        parameters.extend(order_parameters(order, &format!("orders[{}]", index)));
+    }
+    if let Some(deadline) = batch.format_deadline() {
+        parameters.push((String::from("deadline"), deadline));
+    }
+    if payload.validate {
+        parameters.push((String::from("validate"), String::from("true")));
+    }
+    encode_owned_parameters(nonce, &parameters, otp)
+}
+
+fn encode_edit_order_payload(
+    payload: &EditOrderRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let edit = &payload.edit;
+    let mut parameters = vec![
+        ("txid", edit.reference().value()),
+        ("pair", edit.pair().clone()),
+    ];
+    if let Some(volume) = edit.volume() {
+        parameters.push(("volume", edit.format_volume(volume)));
+    }
+    if let Some(price) = edit.price() {
+        parameters.push((
+            "price",
+            signed_price(edit.order_type(), edit.format_price(price)),
+        ));
+    }
+    if let Some(price2) = edit.price2() {
+        parameters.push(("price2", edit.format_price(price2)));
+    }
+    if !edit.oflags().is_empty() {
+        let oflags = edit
+            .oflags()
+            .iter()
+            .map(|oflag| oflag.value())
+            .collect::<Vec<&str>>()
+            .join(",");
+        parameters.push(("oflags", oflags));
+    }
+    if let Some(deadline) = edit.format_deadline() {
+        parameters.push(("deadline", deadline));
+    }
+    if payload.validate {
+        parameters.push(("validate", String::from("true")));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+//AmendOrder splits prices into the limit and the trigger price instead of
+//kraken's usual positional price and price2
+fn encode_amend_order_payload(
+    payload: &AmendOrderRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let edit = &payload.edit;
+    let mut parameters = vec![("txid", edit.reference().value())];
+    if let Some(volume) = edit.volume() {
+        parameters.push(("order_qty", edit.format_volume(volume)));
+    }
+    if let Some(price) = edit.price() {
+        let key = match edit.order_type() {
+            OrderType::Limit => "limit_price",
+            _ => "trigger_price",
+        };
+        parameters.push((key, edit.format_price(price)));
+    }
+    if let Some(price2) = edit.price2() {
+        parameters.push(("limit_price", edit.format_price(price2)));
+    }
+    if edit.oflags().contains(&OrderFlag::Post) {
+        parameters.push(("post_only", String::from("true")));
+    }
+    if let Some(deadline) = edit.format_deadline() {
+        parameters.push(("deadline", deadline));
+    }
+    encode_parameters(nonce, &parameters, otp)
+}
+
+//Trailing stops take their trigger price as an offset from the market price,
+//which kraken expects to be explicitly signed
+fn signed_price(order_type: OrderType, price: String) -> String {
+    match order_type {
+        OrderType::TrailingStop | OrderType::TrailingStopLimit => format!("+{}", price),
+        _ => price,
+    }
+}
+
+fn encode_cancel_order_batch_payload(
+    payload: &CancelOrderBatchRequestPayload,
+    nonce: &Nonce,
+    otp: &String,
+) -> String {
+    let parameters = payload
+        .batch
+        .references()
+        .iter()
+        .enumerate()
+        .map(|(index, reference)| (format!("orders[{}]", index), reference.value()))
+        .collect::<Vec<(String, String)>>();
+    encode_owned_parameters(nonce, &parameters, otp)
+}
+
+fn encode_owned_parameters(nonce: &Nonce, parameters: &[(String, String)], otp: &String) -> String {
+    let parameters = parameters
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.clone()))
+        .collect::<Vec<(&str, String)>>();
+    encode_parameters(nonce, &parameters, otp)
+}
+
+fn nested_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        String::from(key)
+    } else {
+        format!("{}[{}]", prefix, key)
+    }
+}
+
+fn order_parameters(order: &NewOrder, prefix: &str) -> Vec<(String, String)> {
+    let mut parameters = vec![
+        (
+            nested_key(prefix, "ordertype"),
+            String::from(order.order_type().value()),
+        ),
+        (
+            nested_key(prefix, "type"),
+            String::from(order.position().value()),
+        ),
+        (nested_key(prefix, "volume"), order.format_volume()),
+    ];
+    if let Some(price) = order.price() {
+        parameters.push((
+            nested_key(prefix, "price"),
+            signed_price(order.order_type(), order.format_price(price)),
+        ));
+    }
+    if let Some(price2) = order.price2() {
+        parameters.push((nested_key(prefix, "price2"), order.format_price(price2)));
+    }
+    if let Some(leverage) = order.leverage() {
+        parameters.push((nested_key(prefix, "leverage"), leverage.to_string()));
+    }
+    if !order.oflags().is_empty() {
+        let oflags = order
+            .oflags()
+            .iter()
+            .map(|oflag| oflag.value())
+            .collect::<Vec<&str>>()
+            .join(",");
+        parameters.push((nested_key(prefix, "oflags"), oflags));
+    }
+    if let Some(time_in_force) = order.time_in_force() {
+        parameters.push((
+            nested_key(prefix, "timeinforce"),
+            String::from(time_in_force.value()),
+        ));
+    }
+    if let Some(start_time) = order.start_time() {
+        parameters.push((nested_key(prefix, "starttm"), start_time.value()));
+    }
+    if let Some(expire_time) = order.expire_time() {
+        parameters.push((nested_key(prefix, "expiretm"), expire_time.value()));
+    }
+    if let Some(userref) = order.userref() {
+        parameters.push((nested_key(prefix, "userref"), userref.to_string()));
+    }
+    if let Some(close) = order.close() {
+        let close_prefix = nested_key(prefix, "close");
+        parameters.push((
+            nested_key(&close_prefix, "ordertype"),
+            String::from(close.order_type().value()),
+        ));
+        parameters.push((
+            nested_key(&close_prefix, "price"),
+            order.format_price(close.price()),
+        ));
+        if let Some(price2) = close.price2() {
+            parameters.push((
+                nested_key(&close_prefix, "price2"),
+                order.format_price(price2),
+            ));
+        }
+    }
+    parameters
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::nonce::Nonce;
+    use chrono::{TimeZone, Utc};
+    use lootag_cryptoassignment_domain::{
+        earn::{self, LockType},
+        export::{self, ExportFormat, ReportType},
+        ledger_entry::LedgerEntryType,
+        new_order::{self, OrderFlag, ScheduledTime},
+        open_order_description::{OrderType, Position},
+        order_batch, order_cancellation,
+        order_edit::{self, OrderReference},
+        test_fixtures::asset_pair,
+        transfer::{self, Wallet},
+        withdrawal,
+    };
+
+    use crate::{
+        filter::{CloseTime, ClosedOrdersFilter, LedgersFilter, TradeType, TradesHistoryFilter},
+        nonce::Nonce,
+    };
 
-    use super::{encode_impl, OpenOrdersRequestPayload, RequestPayload};
+    use super::{
+        encode_impl, AddExportRequestPayload, AddOrderBatchRequestPayload, AddOrderRequestPayload,
+        AmendOrderRequestPayload, BalanceRequestPayload, CancelOrderBatchRequestPayload,
+        ClosedOrdersRequestPayload, DepositAddressesRequestPayload, EarnAllocateRequestPayload,
+        LedgersRequestPayload, OpenOrdersRequestPayload, OpenPositionsRequestPayload,
+        QueryOrdersRequestPayload, RequestPayload, TradeBalanceRequestPayload,
+        TradesHistoryRequestPayload, WalletTransferRequestPayload, WithdrawRequestPayload,
+    };
 
     #[test]
     fn should_encode_return_correctly_encoded_payload() {
@@ -47,4 +942,420 @@ mod tests {
             String::from("nonce=1234567&trades=true&otp=123456")
         );
     }
+
+    #[test]
+    fn should_encode_return_only_nonce_and_otp_for_balance_payload() {
+        //Arrange
+        let payload = RequestPayload::Balance(BalanceRequestPayload {});
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(encoded_payload, String::from("nonce=1234567&otp=123456"));
+    }
+
+    #[test]
+    fn should_encode_include_asset_in_trade_balance_payload() {
+        //Arrange
+        let payload = RequestPayload::TradeBalance(TradeBalanceRequestPayload {
+            asset: Some(String::from("ZEUR")),
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&asset=ZEUR&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_include_only_provided_filters_in_closed_orders_payload() {
+        //Arrange
+        let filter = ClosedOrdersFilter::new(Some(1688671834), None, CloseTime::Close, None, true);
+        let payload = RequestPayload::ClosedOrders(ClosedOrdersRequestPayload {
+            filter: filter,
+            ofs: Some(50),
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from(
+                "nonce=1234567&trades=true&start=1688671834&ofs=50&closetime=close&otp=123456"
+            )
+        );
+    }
+
+    #[test]
+    fn should_encode_join_txids_in_query_orders_payload() {
+        //Arrange
+        let payload = RequestPayload::QueryOrders(QueryOrdersRequestPayload {
+            txids: vec![
+                String::from("OBCMZD-JIEE7-77TH3F"),
+                String::from("OMMDB2-FSB6Z-7W3HPO"),
+            ],
+            trades: false,
+            userref: None,
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from(
+                "nonce=1234567&txid=OBCMZD-JIEE7-77TH3F%2COMMDB2-FSB6Z-7W3HPO&trades=false&otp=123456"
+            )
+        );
+    }
+
+    #[test]
+    fn should_encode_escape_trade_type_in_trades_history_payload() {
+        //Arrange
+        let filter = TradesHistoryFilter::new(TradeType::NoPosition, None, None, false);
+        let payload = RequestPayload::TradesHistory(TradesHistoryRequestPayload {
+            filter: filter,
+            ofs: None,
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&type=no%20position&consolidate_taker=false&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_default_to_all_assets_in_ledgers_payload() {
+        //Arrange
+        let filter = LedgersFilter::new(vec![], Some(LedgerEntryType::Deposit), None, None);
+        let payload = RequestPayload::Ledgers(LedgersRequestPayload {
+            filter: filter,
+            ofs: Some(50),
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&asset=all&type=deposit&ofs=50&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_escape_add_order_parameters() {
+        //Arrange
+        let close = new_order::new_close_order(OrderType::StopLoss, 28000.0, None).unwrap();
+        let order = new_order::builder(
+            String::from("XXBTZUSD"),
+            Position::Buy,
+            OrderType::Limit,
+            1.25,
+        )
+        .price(30000.5)
+        .oflag(OrderFlag::Post)
+        .oflag(OrderFlag::FeeInQuote)
+        .expire_time(ScheduledTime::In(3600))
+        .close(close)
+        .build(&asset_pair())
+        .unwrap();
+        let payload = RequestPayload::AddOrder(AddOrderRequestPayload {
+            order: order,
+            validate: true,
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from(
+                "nonce=1234567&pair=XXBTZUSD&ordertype=limit&type=buy&volume=1.25000000\
+                &price=30000.5&oflags=post%2Cfciq&expiretm=%2B3600\
+                &close%5Bordertype%5D=stop-loss&close%5Bprice%5D=28000.0&validate=true&otp=123456"
+            )
+        );
+    }
+
+    #[test]
+    fn should_encode_nest_batch_orders_under_their_index() {
+        //Arrange
+        let close = new_order::new_close_order(OrderType::StopLoss, 28000.0, None).unwrap();
+        let orders = vec![
+            new_order::builder(
+                String::from("XXBTZUSD"),
+                Position::Buy,
+                OrderType::Limit,
+                0.5,
+            )
+            .price(30000.0)
+            .close(close),
+            new_order::builder(
+                String::from("XXBTZUSD"),
+                Position::Sell,
+                OrderType::Limit,
+                0.5,
+            )
+            .price(31000.0),
+        ];
+        let deadline = Utc
+            .timestamp_opt(Utc::now().timestamp() + 30, 0)
+            .unwrap();
+        let batch = order_batch::new(&asset_pair(), orders, Some(deadline)).unwrap();
+        let payload = RequestPayload::AddOrderBatch(AddOrderBatchRequestPayload {
+            batch: batch,
+            validate: false,
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            format!(
+                "nonce=1234567&pair=XXBTZUSD\
+                &orders%5B0%5D%5Bordertype%5D=limit&orders%5B0%5D%5Btype%5D=buy\
+                &orders%5B0%5D%5Bvolume%5D=0.50000000&orders%5B0%5D%5Bprice%5D=30000.0\
+                &orders%5B0%5D%5Bclose%5D%5Bordertype%5D=stop-loss\
+                &orders%5B0%5D%5Bclose%5D%5Bprice%5D=28000.0\
+                &orders%5B1%5D%5Bordertype%5D=limit&orders%5B1%5D%5Btype%5D=sell\
+                &orders%5B1%5D%5Bvolume%5D=0.50000000&orders%5B1%5D%5Bprice%5D=31000.0\
+                &deadline={}&otp=123456",
+                deadline.format("%Y-%m-%dT%H%%3A%M%%3A%S.000Z")
+            )
+        );
+    }
+
+    #[test]
+    fn should_encode_split_amended_prices_into_trigger_and_limit() {
+        //Arrange
+        let reference = OrderReference::Txid(String::from("OHYO67-6LP66-HMQ437"));
+        let edit = order_edit::builder(reference, OrderType::StopLossLimit)
+            .price(28000.0)
+            .price2(27900.0)
+            .build(&asset_pair())
+            .unwrap();
+        let payload = RequestPayload::AmendOrder(AmendOrderRequestPayload { edit: edit });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from(
+                "nonce=1234567&txid=OHYO67-6LP66-HMQ437\
+                &trigger_price=28000.0&limit_price=27900.0&otp=123456"
+            )
+        );
+    }
+
+    #[test]
+    fn should_encode_cancel_batch_mixing_txids_and_userrefs() {
+        //Arrange
+        let references = vec![
+            OrderReference::Txid(String::from("OHYO67-6LP66-HMQ437")),
+            OrderReference::Userref(42),
+        ];
+        let batch = order_cancellation::new_batch(references).unwrap();
+        let payload =
+            RequestPayload::CancelOrderBatch(CancelOrderBatchRequestPayload { batch: batch });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from(
+                "nonce=1234567&orders%5B0%5D=OHYO67-6LP66-HMQ437&orders%5B1%5D=42&otp=123456"
+            )
+        );
+    }
+
+    #[test]
+    fn should_encode_consolidated_positions_by_market() {
+        //Arrange
+        let payload = RequestPayload::OpenPositions(OpenPositionsRequestPayload {
+            txids: vec![],
+            docalcs: true,
+            consolidated: true,
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&docalcs=true&consolidation=market&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_escape_deposit_method_names() {
+        //Arrange
+        let payload = RequestPayload::DepositAddresses(DepositAddressesRequestPayload {
+            asset: String::from("XBT"),
+            method: String::from("Bitcoin Lightning"),
+            new: true,
+        });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&asset=XBT&method=Bitcoin%20Lightning&new=true&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_add_the_withdrawal_address_and_max_fee_when_provided() {
+        //Arrange
+        let info = withdrawal::new_info(
+            String::from("XBT"),
+            String::from("cold storage"),
+            String::from("Bitcoin"),
+            2.0,
+            0.4999,
+            0.0001,
+        )
+        .unwrap();
+        let request = withdrawal::new_request(
+            String::from("XBT"),
+            String::from("cold storage"),
+            0.5,
+            Some(String::from("bc1qxdsh4sdd29h6ldehz0se5c61asq8cgwyjf2y3z")),
+            Some(0.0002),
+            8,
+            &info,
+        )
+        .unwrap();
+        let payload = RequestPayload::Withdraw(WithdrawRequestPayload { request: request });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&asset=XBT&key=cold%20storage&amount=0.50000000&address=bc1qxdsh4sdd29h6ldehz0se5c61asq8cgwyjf2y3z&max_fee=0.00020000&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_name_the_wallets_the_way_kraken_expects() {
+        //Arrange
+        let transfer =
+            transfer::new_wallet_transfer(String::from("XBT"), Wallet::Spot, Wallet::Futures, 0.25)
+                .unwrap();
+        let payload =
+            RequestPayload::WalletTransfer(WalletTransferRequestPayload { transfer: transfer });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&asset=XBT&from=Spot%20Wallet&to=Futures%20Wallet&amount=0.25&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_send_the_amount_and_strategy_of_an_allocation() {
+        //Arrange
+        let strategy = earn::new_strategy(
+            String::from("ESRFUO3-Q62XD-WIOIL7"),
+            String::from("DOT"),
+            LockType::Instant,
+            None,
+            None,
+            true,
+            true,
+        );
+        let request = earn::new_allocate_request(&strategy, 12.5).unwrap();
+        let payload = RequestPayload::EarnAllocate(EarnAllocateRequestPayload { request: request });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&amount=12.5&strategy_id=ESRFUO3-Q62XD-WIOIL7&otp=123456")
+        );
+    }
+
+    #[test]
+    fn should_encode_add_the_export_time_range_when_provided() {
+        //Arrange
+        let request = export::new_request(
+            ReportType::Ledgers,
+            ExportFormat::Csv,
+            String::from("2022 ledgers"),
+            Some(1640995200),
+            Some(1672531200),
+        )
+        .unwrap();
+        let payload = RequestPayload::AddExport(AddExportRequestPayload { request: request });
+        let nonce = Nonce { value: 1234567 };
+        let otp = String::from("123456");
+
+        //Act
+        let encoded_payload = encode_impl(&payload, &nonce, &otp);
+
+        //Assert
+        assert_eq!(
+            encoded_payload,
+            String::from("nonce=1234567&report=ledgers&format=CSV&description=2022%20ledgers&starttm=1640995200&endtm=1672531200&otp=123456")
+        );
+    }
 }
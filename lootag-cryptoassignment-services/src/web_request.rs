@@ -1,10 +1,11 @@
-use std::{ascii::AsciiExt, io::ErrorKind};
+use std::ascii::AsciiExt;
 
 use lootag_cryptoassignment_domain::credentials::Credentials;
 
 use sha2::{Digest, Sha256};
 
 use crate::{
+    error::KrakenError,
     nonce::Nonce,
     payload::{self, encode, RequestPayload},
     uri::Uri,
@@ -37,18 +38,18 @@ impl WebRequest {
     }
 }
 
-pub fn encoded_payload(web_request: &WebRequest) -> Result<String, std::io::Error> {
+pub fn encoded_payload(web_request: &WebRequest) -> Result<String, KrakenError> {
     encoded_payload_impl(web_request, payload::encode)
 }
 
-pub fn api_sign(web_request: &WebRequest) -> Result<String, std::io::Error> {
+pub fn api_sign(web_request: &WebRequest) -> Result<String, KrakenError> {
     api_sign_impl(web_request, encode)
 }
 
 fn encoded_payload_impl(
     web_request: &WebRequest,
     encoded_payload: fn(&RequestPayload, &Nonce, &String) -> String,
-) -> Result<String, std::io::Error> {
+) -> Result<String, KrakenError> {
     Ok(encoded_payload(
         &web_request.payload,
         &web_request.nonce,
@@ -57,7 +58,7 @@ fn encoded_payload_impl(
 }
 
 //Not tested because it's just a wrapper around an third-party library
-fn totp(web_request: &WebRequest) -> Result<String, std::io::Error> {
+fn totp(web_request: &WebRequest) -> Result<String, KrakenError> {
     let password_duration_in_seconds = 30;
     let clockskew = 0;
     Ok(otp::make_totp(
@@ -69,14 +70,14 @@ fn totp(web_request: &WebRequest) -> Result<String, std::io::Error> {
         password_duration_in_seconds,
         clockskew,
     )
-    .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?
+    .map_err(|e| KrakenError::Signing(e.to_string()))?
     .to_string())
 }
 
 fn api_sign_impl(
     web_request: &WebRequest,
     encoded_payload: fn(&RequestPayload, &Nonce, &String) -> String,
-) -> Result<String, std::io::Error> {
+) -> Result<String, KrakenError> {
     let nonce_and_payload = format!(
         "{}{}",
         web_request.nonce.value.to_string(),
@@ -93,7 +94,7 @@ fn api_sign_impl(
     let encoded_uri = web_request.uri.value().as_bytes().to_vec();
     let message = [&encoded_uri[..], &sha256_encoded_nonce_and_payload[..]].concat();
     let decoded_private_key = base64::decode(web_request.credentials.private_key())
-        .map_err(|e| std::io::Error::new(ErrorKind::InvalidData, e.to_string()))?;
+        .map_err(|e| KrakenError::Signing(e.to_string()))?;
     let hmac_key = ring::hmac::Key::new(ring::hmac::HMAC_SHA512, &decoded_private_key);
     let encoded_signature = base64::encode(ring::hmac::sign(&hmac_key, &message).as_ref());
     Ok(encoded_signature)